@@ -42,6 +42,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use rusty_ulid::*;
+use std::hint::black_box;
 use std::str::FromStr;
 
 #[allow(clippy::redundant_closure)]
@@ -103,6 +104,20 @@ fn criterion_benchmark(c: &mut Criterion) {
             string
         })
     });
+
+    let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    c.bench_function("ulid_to_string", |b| b.iter(|| black_box(ulid).to_string()));
+
+    let ulids: Vec<Ulid> = (0..1000u128)
+        .map(|index| Ulid::from(index.wrapping_mul(0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835)))
+        .collect();
+    c.bench_function("sort_1000_ulids", |b| {
+        b.iter(|| {
+            let mut ulids = ulids.clone();
+            ulids.sort_unstable();
+            ulids
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,151 @@
+/*
+ * The MIT License (MIT)
+ * Copyright (c) 2018-2023 Joern Huxhorn
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the “Software”), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+/*
+ * Copyright 2018-2023 Joern Huxhorn
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![forbid(unsafe_code)]
+
+//! End-to-end benchmark scenarios reporting throughput in elements per second.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use rusty_ulid::Ulid;
+use std::hint::black_box;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const BATCH_SIZES: [usize; 3] = [16, 256, 4096];
+const THREAD_COUNTS: [usize; 3] = [1, 2, 4];
+const ULIDS_PER_THREAD: usize = 1_000;
+const LOG_LINES: usize = 1_000;
+
+fn single_threaded_generation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_threaded_generation");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("generate", |b| b.iter(Ulid::generate));
+
+    group.bench_function("next_monotonic", |b| {
+        let mut previous = Ulid::generate();
+        b.iter(|| {
+            previous = Ulid::next_monotonic(previous);
+            previous
+        })
+    });
+
+    group.finish();
+}
+
+fn contended_monotonic_generation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended_monotonic_generation");
+
+    for threads in THREAD_COUNTS {
+        group.throughput(Throughput::Elements((threads * ULIDS_PER_THREAD) as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |b, &threads| {
+                let shared = Arc::new(Mutex::new(Ulid::generate()));
+                b.iter(|| {
+                    let handles: Vec<_> = (0..threads)
+                        .map(|_| {
+                            let shared = Arc::clone(&shared);
+                            thread::spawn(move || {
+                                for _ in 0..ULIDS_PER_THREAD {
+                                    let mut previous = shared.lock().unwrap();
+                                    *previous = Ulid::next_monotonic(*previous);
+                                    black_box(*previous);
+                                }
+                            })
+                        })
+                        .collect();
+                    for handle in handles {
+                        handle.join().unwrap();
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn batch_generation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_generation");
+
+    for size in BATCH_SIZES {
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| Ulid::generate_batch(size))
+        });
+    }
+
+    group.finish();
+}
+
+fn log_ingestion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("log_ingestion");
+    group.throughput(Throughput::Elements(LOG_LINES as u64));
+
+    let mut previous = Ulid::generate();
+    let lines: Vec<String> = (0..LOG_LINES)
+        .map(|index| {
+            previous = Ulid::next_monotonic(previous);
+            format!("{previous} INFO request {index} handled")
+        })
+        .collect();
+
+    group.bench_function("parse_leading_ulid", |b| {
+        b.iter(|| {
+            lines
+                .iter()
+                .filter_map(|line| line.get(..26)?.parse::<Ulid>().ok())
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    single_threaded_generation,
+    contended_monotonic_generation,
+    batch_generation,
+    log_ingestion
+);
+criterion_main!(benches);
@@ -0,0 +1,1293 @@
+/*
+ * The MIT License (MIT)
+ * Copyright (c) 2018-2023 Joern Huxhorn
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the “Software”), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+/*
+ * Copyright 2018-2023 Joern Huxhorn
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![deny(missing_docs)]
+#![allow(dead_code)]
+#![forbid(unsafe_code)]
+
+//! # Command line tool for generating and validating ULIDs
+
+use rusty_ulid::{ParseMode, Ulid};
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Exit code if any of the checked values is invalid.
+const EXIT_INVALID: i32 = 1;
+/// Exit code if the command line arguments are invalid.
+const EXIT_USAGE: i32 = 2;
+/// Exit code if writing the output failed, e.g. because of a closed pipe.
+const EXIT_IO: i32 = 3;
+static HELP: &str = "rusty_ulid
+
+Usage:
+    rusty_ulid [options]
+        Generate ULIDs.
+
+    rusty_ulid [options] <args>...
+        Check ULIDs given as args. Hyphenated UUIDs are converted to ULIDs.
+
+    rusty_ulid inspect [options] [<args>...]
+        Print the components of the ULIDs given as args, or of a generated
+        ULID. `decode` is an alias of `inspect`.
+
+    rusty_ulid bench [--duration <duration>]
+        Measure how many ULIDs per second are generated and parsed.
+
+Options:
+    -h, --help          Display this message and exit
+    -V, --version       Print version info and exit
+    -v, --verbose       Use verbose output
+    -0, --null          Terminate each printed ULID with NUL instead of
+                        newline, e.g. for `xargs -0`
+    -n, --count <n>     Generate <n> ULIDs instead of one
+    --every <interval>  Keep generating a ULID every <interval>, e.g.
+                        `500ms` or `1s`, until interrupted or <n> ULIDs
+                        were generated
+    --at <time>         Generate ULIDs with the given timestamp, either in
+                        milliseconds since the UNIX epoch or as RFC 3339
+    --min <time>        Print the smallest ULID of the given millisecond
+                        instead of generating ULIDs
+    --max <time>        Print the largest ULID of the given millisecond
+                        instead of generating ULIDs
+    --strict            Reject checked ULIDs that aren't canonical, i.e.
+                        contain lowercase letters or the aliases i, l or o
+    --lenient           Additionally accept checked ULIDs containing
+                        hyphens between groups, e.g. `01CAH-7NXGR-...`
+    --file <path>       Check the ULIDs listed in the given file, one per
+                        line, in addition to args. Can be repeated
+    --since <time>      Print checked ULIDs not older than the given time
+    --until <time>      Print checked ULIDs not newer than the given time
+    --format <format>   Print ULIDs as `ulid` (default), as hyphenated
+                        `uuid`, as 32 `hex` digits or as 16 `raw` bytes
+                        without separator, in addition to the ULID if
+                        verbose and not raw
+    --uuid              Same as --format uuid
+    --duration <d>      Duration of each bench measurement, e.g. `500ms`,
+                        `5s` or `1m`, defaults to 1s
+    --color <when>      Colorize timestamp and random part of verbose
+                        output `always`, `never` or `auto` (default), i.e.
+                        if printing to a terminal and NO_COLOR isn't set
+
+Exit codes:
+    0   Success
+    1   At least one of the checked values is invalid, each one is reported
+        on stderr
+    2   Invalid command line arguments or unreadable file
+    3   Writing the output failed, e.g. because of a closed pipe
+";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let exit_code = main_with_args_and_return_value(args);
+    std::process::exit(exit_code);
+}
+
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+fn generate_ulids(count: usize, at: Option<u64>, format: Format) -> i32 {
+    // strictly increasing, so bulk output is sorted
+    let ulids: Box<dyn Iterator<Item = Ulid>> = match at {
+        None => Box::new(Ulid::iter_monotonic()),
+        Some(timestamp) => Box::new(std::iter::successors(
+            Some(Ulid::generate_with_timestamp(timestamp)),
+            |ulid| Some(ulid.increment()),
+        )),
+    };
+
+    let mut out = io::BufWriter::new(io::stdout().lock());
+    for ulid in ulids.take(count) {
+        if print(&mut out, &ulid, format).is_err() {
+            // e.g. closed pipe, nobody is listening anymore
+            return EXIT_IO;
+        }
+    }
+
+    if out.flush().is_err() {
+        return EXIT_IO;
+    }
+
+    0
+}
+
+#[cfg(not(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+)))]
+fn generate_ulids(_count: usize, _at: Option<u64>, _format: Format) -> i32 {
+    println!("Generation of ULID not supported.");
+
+    1
+}
+
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+fn generate_ulids_every(interval: Duration, count: Option<usize>, format: Format) -> i32 {
+    let mut out = io::stdout().lock();
+    let start = Instant::now();
+    let mut previous: Option<Ulid> = None;
+    for index in 0..count.unwrap_or(usize::MAX) {
+        // relative to start, so the cadence doesn't drift
+        let due = interval.saturating_mul(u32::try_from(index).unwrap_or(u32::MAX));
+        std::thread::sleep(due.saturating_sub(start.elapsed()));
+
+        let ulid = previous.map_or_else(Ulid::generate, Ulid::next_monotonic);
+        previous = Some(ulid);
+        // flushed right away, the next ULID may take a while
+        if print(&mut out, &ulid, format).is_err() || out.flush().is_err() {
+            return EXIT_IO;
+        }
+    }
+
+    0
+}
+
+#[cfg(not(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+)))]
+fn generate_ulids_every(_interval: Duration, _count: Option<usize>, _format: Format) -> i32 {
+    println!("Generation of ULID not supported.");
+
+    1
+}
+
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+fn bench(duration: Duration) -> i32 {
+    let generated = measure(duration, Ulid::generate);
+    println!("generate: {generated:>12.0} ULIDs/s");
+
+    let strings: Vec<String> = (0..1024).map(|_| Ulid::generate().to_string()).collect();
+    let mut index = 0;
+    let parsed = measure(duration, || {
+        index = (index + 1) % strings.len();
+        strings[index].parse::<Ulid>()
+    });
+    println!("parse:    {parsed:>12.0} ULIDs/s");
+
+    0
+}
+
+#[cfg(not(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+)))]
+fn bench(_duration: Duration) -> i32 {
+    println!("Generation of ULID not supported.");
+
+    1
+}
+
+/// Calls `f` repeatedly for `duration` and returns the number of calls per second.
+fn measure<T>(duration: Duration, mut f: impl FnMut() -> T) -> f64 {
+    // checking the clock after every call would dominate the measurement
+    const BATCH_SIZE: u32 = 1000;
+
+    let start = Instant::now();
+    let mut calls: u64 = 0;
+    while start.elapsed() < duration {
+        for _ in 0..BATCH_SIZE {
+            let _ = std::hint::black_box(f());
+        }
+        calls += u64::from(BATCH_SIZE);
+    }
+
+    calls as f64 / start.elapsed().as_secs_f64()
+}
+
+/// Parses `value` as duration like `500ms`, `5s`, `1m` or `1h`. A number
+/// without unit is interpreted as seconds.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let split = value
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number.parse().ok()?;
+
+    match unit {
+        "ms" => Some(Duration::from_millis(number)),
+        "" | "s" => Some(Duration::from_secs(number)),
+        "m" => Some(Duration::from_secs(number.checked_mul(60)?)),
+        "h" => Some(Duration::from_secs(number.checked_mul(3600)?)),
+        _ => None,
+    }
+}
+
+/// How ULIDs are printed.
+#[derive(Debug, Default, Clone, Copy)]
+struct Format {
+    verbose: bool,
+    representation: Representation,
+    inspect: bool,
+    color: bool,
+    null: bool,
+}
+
+/// The representation of printed ULIDs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Representation {
+    #[default]
+    Ulid,
+    Uuid,
+    Hex,
+    Raw,
+}
+
+impl Representation {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ulid" => Some(Self::Ulid),
+            "uuid" => Some(Self::Uuid),
+            "hex" => Some(Self::Hex),
+            "raw" => Some(Self::Raw),
+            _ => None,
+        }
+    }
+
+    fn write(self, out: &mut impl Write, ulid: &Ulid) -> io::Result<()> {
+        match self {
+            Self::Ulid => writeln!(out, "{ulid}"),
+            Self::Uuid => writeln!(out, "{}", ulid.to_uuid_string()),
+            Self::Hex => writeln!(out, "{}", ulid.to_hex()),
+            Self::Raw => out.write_all(ulid.as_bytes()),
+        }
+    }
+}
+
+/// The value of the `--color` option.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Returns whether output is colorized, following https://no-color.org.
+    fn is_enabled(self, is_terminal: bool, no_color: Option<&OsStr>) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => is_terminal && no_color.map_or(true, OsStr::is_empty),
+        }
+    }
+}
+
+// ANSI escape sequences of the colors of timestamp and random part
+const TIMESTAMP_COLOR: &str = "\x1b[36m";
+const RANDOM_COLOR: &str = "\x1b[33m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+fn print(out: &mut impl Write, ulid: &Ulid, format: Format) -> io::Result<()> {
+    if !format.null || format.representation == Representation::Raw {
+        return print_record(out, ulid, format);
+    }
+
+    let mut record = Vec::new();
+    print_record(&mut record, ulid, format)?;
+    // terminated by NUL instead of the newline and blank line of verbose output
+    while record.last() == Some(&b'\n') {
+        let _ = record.pop();
+    }
+    record.push(b'\0');
+    out.write_all(&record)
+}
+
+fn print_record(out: &mut impl Write, ulid: &Ulid, format: Format) -> io::Result<()> {
+    if format.inspect {
+        return inspect(out, ulid);
+    }
+
+    let representation = format.representation;
+    if !format.verbose || representation == Representation::Raw {
+        return representation.write(out, ulid);
+    }
+
+    if format.color {
+        let string = ulid.to_string();
+        let (timestamp, random) = string.split_at(10);
+        writeln!(
+            out,
+            "{TIMESTAMP_COLOR}{timestamp}{RANDOM_COLOR}{random}{RESET_COLOR}"
+        )?;
+    } else {
+        writeln!(out, "{ulid}")?;
+    }
+    if representation != Representation::Ulid {
+        representation.write(out, ulid)?;
+    }
+    if let Some(datetime) = datetime_string(ulid) {
+        writeln!(out, "{datetime}\n")?;
+    }
+    Ok(())
+}
+
+fn inspect(out: &mut impl Write, ulid: &Ulid) -> io::Result<()> {
+    let value = u128::from(*ulid);
+
+    writeln!(out, "ULID:      {ulid}")?;
+    writeln!(out, "UUID:      {}", ulid.to_uuid_string())?;
+    writeln!(out, "timestamp: {}", ulid.timestamp())?;
+    if let Some(datetime) = datetime_string(ulid) {
+        writeln!(out, "datetime:  {datetime}")?;
+    }
+    writeln!(out, "random:    {:020x}", value & ((1 << 80) - 1))?;
+    writeln!(out, "u128:      {value}")?;
+    writeln!(out, "bytes:     {:02x?}\n", ulid.as_bytes())
+}
+
+#[cfg(feature = "time")]
+fn datetime_string(ulid: &Ulid) -> Option<String> {
+    use time::format_description::well_known::Rfc3339;
+
+    ulid.offsetdatetime().format(&Rfc3339).ok()
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+fn datetime_string(ulid: &Ulid) -> Option<String> {
+    use chrono::SecondsFormat;
+
+    Some(ulid.datetime().to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+fn datetime_string(_ulid: &Ulid) -> Option<String> {
+    None
+}
+
+/// Parses `value` as milliseconds since the UNIX epoch or as RFC 3339 date-time.
+fn parse_timestamp(value: &str) -> Option<u64> {
+    let timestamp = if value.bytes().all(|byte| byte.is_ascii_digit()) {
+        value.parse().ok()?
+    } else {
+        parse_rfc3339(value)?
+    };
+
+    (timestamp <= 0xFFFF_FFFF_FFFF).then_some(timestamp)
+}
+
+#[cfg(feature = "time")]
+fn parse_rfc3339(value: &str) -> Option<u64> {
+    use time::format_description::well_known::Rfc3339;
+    use time::OffsetDateTime;
+
+    let datetime = OffsetDateTime::parse(value, &Rfc3339).ok()?;
+    u64::try_from(datetime.unix_timestamp_nanos() / 1_000_000).ok()
+}
+
+#[cfg(all(feature = "chrono", not(feature = "time")))]
+fn parse_rfc3339(value: &str) -> Option<u64> {
+    let datetime = chrono::DateTime::parse_from_rfc3339(value).ok()?;
+    u64::try_from(datetime.timestamp_millis()).ok()
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+fn parse_rfc3339(_value: &str) -> Option<u64> {
+    None
+}
+
+/// Returns `true` if the timestamp of `ulid` is within `since` and `until`, both inclusive.
+fn is_in_window(ulid: &Ulid, since: Option<u64>, until: Option<u64>) -> bool {
+    let timestamp = ulid.timestamp();
+
+    !since.is_some_and(|since| timestamp < since) && !until.is_some_and(|until| until < timestamp)
+}
+
+/// Returns the value following the option `name`, parsed by `parse`.
+///
+/// Prints an error and returns `None` if the value is missing or invalid.
+fn option_value<T>(
+    args: &mut impl Iterator<Item = String>,
+    name: &str,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> Option<T> {
+    let Some(value) = args.next() else {
+        eprintln!("Missing value for {name}");
+        return None;
+    };
+
+    let parsed = parse(&value);
+    if parsed.is_none() {
+        eprintln!("Invalid value for {name}: {value}");
+    }
+    parsed
+}
+
+fn main_with_args_and_return_value(args: Vec<String>) -> i32 {
+    let mut format = Format::default();
+    let mut color = ColorChoice::default();
+    let mut help: bool = false;
+    let mut version: bool = false;
+    let mut count: Option<usize> = None;
+    let mut every: Option<Duration> = None;
+    let mut at: Option<u64> = None;
+    let mut boundaries = Vec::<Ulid>::new();
+    let mut since: Option<u64> = None;
+    let mut until: Option<u64> = None;
+    let mut bench_duration: Option<Duration> = None;
+    let mut ulid_candidates = Vec::<String>::new();
+    let mut files = Vec::<String>::new();
+    let mut mode: Option<ParseMode> = None;
+
+    let mut args = args.into_iter().peekable();
+    let mut benchmark = false;
+    match args.peek().map(String::as_str) {
+        Some("inspect" | "decode") => format.inspect = true,
+        Some("bench") => benchmark = true,
+        _ => {}
+    }
+    if format.inspect || benchmark {
+        let _ = args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        let argument: &str = &arg;
+        match argument {
+            "-v" => format.verbose = true,
+            "--verbose" => format.verbose = true,
+            "-0" | "--null" => format.null = true,
+            "--strict" => mode = Some(ParseMode::Strict),
+            "--lenient" => mode = Some(ParseMode::Lenient),
+            "--uuid" => format.representation = Representation::Uuid,
+            "--duration" => {
+                let Some(duration) = option_value(&mut args, argument, parse_duration) else {
+                    return EXIT_USAGE;
+                };
+                bench_duration = Some(duration);
+            }
+            "--color" => {
+                let Some(choice) = option_value(&mut args, argument, ColorChoice::parse) else {
+                    return EXIT_USAGE;
+                };
+                color = choice;
+            }
+            "--format" => {
+                let Some(representation) = option_value(&mut args, argument, Representation::parse)
+                else {
+                    return EXIT_USAGE;
+                };
+                format.representation = representation;
+            }
+            "-h" => help = true,
+            "--help" => help = true,
+            "-V" => version = true,
+            "--version" => version = true,
+            "-n" | "--count" => {
+                let Some(value) = option_value(&mut args, argument, |value| value.parse().ok())
+                else {
+                    return EXIT_USAGE;
+                };
+                count = Some(value);
+            }
+            "--every" => {
+                let Some(interval) = option_value(&mut args, argument, |value| {
+                    parse_duration(value).filter(|interval| !interval.is_zero())
+                }) else {
+                    return EXIT_USAGE;
+                };
+                every = Some(interval);
+            }
+            "--at" => {
+                let Some(timestamp) = option_value(&mut args, argument, parse_timestamp) else {
+                    return EXIT_USAGE;
+                };
+                at = Some(timestamp);
+            }
+            "--min" | "--max" => {
+                let Some(timestamp) = option_value(&mut args, argument, parse_timestamp) else {
+                    return EXIT_USAGE;
+                };
+                boundaries.push(if argument == "--min" {
+                    Ulid::min_for_timestamp(timestamp)
+                } else {
+                    Ulid::max_for_timestamp(timestamp)
+                });
+            }
+            "--since" | "--until" => {
+                let Some(timestamp) = option_value(&mut args, argument, parse_timestamp) else {
+                    return EXIT_USAGE;
+                };
+                if argument == "--since" {
+                    since = Some(timestamp);
+                } else {
+                    until = Some(timestamp);
+                }
+            }
+            "--file" => {
+                let Some(path) = option_value(&mut args, argument, |value| Some(value.to_string()))
+                else {
+                    return EXIT_USAGE;
+                };
+                files.push(path);
+            }
+            _ if argument.starts_with('-') => {
+                eprintln!("Unknown option: {argument}");
+                return EXIT_USAGE;
+            }
+            _ => ulid_candidates.push(argument.to_string()),
+        }
+    }
+
+    format.color = color.is_enabled(
+        io::stdout().is_terminal(),
+        std::env::var_os("NO_COLOR").as_deref(),
+    );
+
+    if version {
+        println!("rusty_ulid {VERSION}");
+        return 0;
+    }
+
+    if help {
+        println!("{HELP}");
+        return 0;
+    }
+
+    if benchmark {
+        return bench(bench_duration.unwrap_or(Duration::from_secs(1)));
+    }
+
+    let mut out = io::stdout().lock();
+    for boundary in &boundaries {
+        if print(&mut out, boundary, format).is_err() {
+            return EXIT_IO;
+        }
+    }
+
+    let checking = !ulid_candidates.is_empty() || !files.is_empty();
+    if !checking && !boundaries.is_empty() {
+        return 0;
+    }
+
+    if !checking {
+        // not checking, producing
+        if let Some(interval) = every {
+            return generate_ulids_every(interval, count, format);
+        }
+        return generate_ulids(count.unwrap_or(1), at, format);
+    }
+
+    let mut exit_code = 0;
+    for candidate in &ulid_candidates {
+        match check(&mut out, candidate, format, mode, since, until) {
+            Ok(true) => {}
+            Ok(false) => exit_code = EXIT_INVALID,
+            Err(_) => return EXIT_IO,
+        }
+    }
+
+    for path in &files {
+        let lines = match File::open(path) {
+            Ok(file) => io::BufReader::new(file).lines(),
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                exit_code = EXIT_USAGE;
+                continue;
+            }
+        };
+        // streamed, the file doesn't need to fit into memory
+        for line in lines {
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => {
+                    eprintln!("{path}: {error}");
+                    exit_code = EXIT_USAGE;
+                    break;
+                }
+            };
+            let candidate = line.trim();
+            if candidate.is_empty() {
+                continue;
+            }
+            match check(&mut out, candidate, format, mode, since, until) {
+                Ok(true) => {}
+                Ok(false) => exit_code = exit_code.max(EXIT_INVALID),
+                Err(_) => return EXIT_IO,
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Checks `candidate`, printing it if requested, and returns whether it's valid.
+fn check(
+    out: &mut impl Write,
+    candidate: &str,
+    format: Format,
+    mode: Option<ParseMode>,
+    since: Option<u64>,
+    until: Option<u64>,
+) -> io::Result<bool> {
+    let is_uuid = is_hyphenated_uuid(candidate);
+    let result = match mode {
+        _ if is_uuid => Ulid::from_uuid_str(candidate),
+        None => candidate.parse(),
+        Some(mode) => Ulid::from_str_with_mode(candidate, mode),
+    };
+    match result {
+        Ok(ulid) => {
+            let print_ulid = format.verbose
+                || format.representation != Representation::Ulid
+                || format.inspect
+                || is_uuid
+                || since.is_some()
+                || until.is_some();
+            if print_ulid && is_in_window(&ulid, since, until) {
+                print(out, &ulid, format)?;
+            }
+            Ok(true)
+        }
+        Err(error) => {
+            eprintln!("{candidate}: {error}");
+            Ok(false)
+        }
+    }
+}
+
+/// Returns whether `candidate` has the layout of a hyphenated UUID, i.e. 36
+/// bytes with hyphens at positions 8, 13, 18 and 23.
+fn is_hyphenated_uuid(candidate: &str) -> bool {
+    let bytes = candidate.as_bytes();
+    bytes.len() == 36 && [8, 13, 18, 23].iter().all(|&index| bytes[index] == b'-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_value_returns_error() {
+        let args = vec!["foo".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_INVALID);
+    }
+
+    #[test]
+    fn valid_values_return_no_error() {
+        let args = vec![
+            "01CB265DSMTDS096TBTZRNTBPC".to_string(),
+            "01CB265J6CRQA44WH98DP3YA07".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn valid_verbose_values_return_no_error() {
+        let args = vec![
+            "01CB265DSMTDS096TBTZRNTBPC".to_string(),
+            "--verbose".to_string(),
+            "01CB265J6CRQA44WH98DP3YA07".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn no_args_return_no_error() {
+        let args = vec![];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn verbose_short_returns_no_error() {
+        let args = vec!["-v".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn verbose_long_returns_no_error() {
+        let args = vec!["--verbose".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[cfg(not(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    )))]
+    #[test]
+    fn no_args_return_no_error() {
+        let args = vec![];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 1);
+    }
+
+    #[cfg(not(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    )))]
+    #[test]
+    fn verbose_short_returns_no_error() {
+        let args = vec!["-v".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 1);
+    }
+
+    #[cfg(not(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    )))]
+    #[test]
+    fn verbose_long_returns_no_error() {
+        let args = vec!["--verbose".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 1);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn count_returns_no_error() {
+        let args = vec!["-n".to_string(), "3".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+
+        let args = vec!["--count".to_string(), "0".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn invalid_count_returns_error() {
+        let args = vec!["--count".to_string(), "many".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+
+        let args = vec!["-n".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[test]
+    fn uuid_values_return_no_error() {
+        let args = vec![
+            "--uuid".to_string(),
+            "01CB265DSMTDS096TBTZRNTBPC".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn invalid_format_returns_usage_error() {
+        let args = vec!["--format".to_string(), "base64".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[test]
+    fn uuid_input_returns_no_error() {
+        let args = vec![
+            "0162a27a-f618-6caa-e4ac-35f1ed787b67".to_string(),
+            "0162A27A-F618-6CAA-E4AC-35F1ED787B67".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn invalid_uuid_input_returns_error() {
+        let args = vec!["0162a27a-f618-6caa-e4ac-35f1ed787b6g".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn non_uuid_input_of_uuid_length_returns_error() {
+        let candidate = "01CB265DSMTDS096TBTZRNTBPC0123456789";
+        assert_eq!(candidate.len(), 36);
+        assert!(!is_hyphenated_uuid(candidate));
+        assert!(is_hyphenated_uuid("0162a27a-f618-6caa-e4ac-35f1ed787b67"));
+        assert!(!is_hyphenated_uuid("0162a27af-618-6caa-e4ac-35f1ed787b67"));
+
+        let result = main_with_args_and_return_value(vec![candidate.to_string()]);
+        assert_eq!(result, EXIT_INVALID);
+    }
+
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn at_returns_no_error() {
+        let args = vec![
+            "--at".to_string(),
+            "2018-04-07T23:39:50.168Z".to_string(),
+            "-n".to_string(),
+            "3".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn invalid_at_returns_error() {
+        let args = vec!["--at".to_string(), "yesterday".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[test]
+    fn boundaries_return_no_error() {
+        let args = vec![
+            "--min".to_string(),
+            "1523144390168".to_string(),
+            "--max".to_string(),
+            "1523144390168".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+
+        let args = vec!["--max".to_string(), "281474976710656".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn time_window_returns_no_error() {
+        let args = vec![
+            "--since".to_string(),
+            "2018-04-14T13:40:26.548Z".to_string(),
+            "--until".to_string(),
+            "1523713231051".to_string(),
+            "01CB265DSMTDS096TBTZRNTBPC".to_string(),
+            "01CB265J6CRQA44WH98DP3YA07".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn window_is_inclusive() {
+        let ulid = Ulid::max_for_timestamp(1000);
+
+        assert!(is_in_window(&ulid, None, None));
+        assert!(is_in_window(&ulid, Some(1000), Some(1000)));
+        assert!(is_in_window(&ulid, Some(999), None));
+        assert!(is_in_window(&ulid, None, Some(1001)));
+        assert!(!is_in_window(&ulid, Some(1001), None));
+        assert!(!is_in_window(&ulid, None, Some(999)));
+    }
+
+    #[test]
+    fn parse_timestamp_test_cases() {
+        assert_eq!(parse_timestamp("1523144390168"), Some(1_523_144_390_168));
+        assert_eq!(parse_timestamp("281474976710655"), Some(0xFFFF_FFFF_FFFF));
+        assert_eq!(parse_timestamp("281474976710656"), None);
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("-1"), None);
+
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        {
+            assert_eq!(
+                parse_timestamp("2018-04-07T23:39:50.168Z"),
+                Some(1_523_144_390_168)
+            );
+            assert_eq!(
+                parse_timestamp("2018-04-08T01:39:50.168+02:00"),
+                Some(1_523_144_390_168)
+            );
+            assert_eq!(parse_timestamp("1969-12-31T23:59:59Z"), None);
+        }
+    }
+
+    #[test]
+    fn print_formats() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let print_to_bytes = |verbose, representation| {
+            let mut out = Vec::new();
+            let format = Format {
+                verbose,
+                representation,
+                ..Format::default()
+            };
+            print(&mut out, &ulid, format).unwrap();
+            out
+        };
+
+        assert_eq!(
+            print_to_bytes(false, Representation::Ulid),
+            b"01CAH7NXGRDJNE9B1NY7PQGYV7\n"
+        );
+        assert_eq!(
+            print_to_bytes(false, Representation::Uuid),
+            b"0162a27a-f618-6caa-e4ac-35f1ed787b67\n"
+        );
+        assert_eq!(
+            print_to_bytes(false, Representation::Hex),
+            b"0162a27af6186caae4ac35f1ed787b67\n"
+        );
+        assert_eq!(print_to_bytes(false, Representation::Raw), ulid.as_bytes());
+        assert_eq!(print_to_bytes(true, Representation::Raw), ulid.as_bytes());
+
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        assert_eq!(
+            print_to_bytes(true, Representation::Uuid),
+            b"01CAH7NXGRDJNE9B1NY7PQGYV7\n0162a27a-f618-6caa-e4ac-35f1ed787b67\n2018-04-07T23:39:50.168Z\n\n"
+        );
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn print_colored() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let mut out = Vec::new();
+        let format = Format {
+            verbose: true,
+            color: true,
+            ..Format::default()
+        };
+
+        print(&mut out, &ulid, format).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[36m01CAH7NXGR\x1b[33mDJNE9B1NY7PQGYV7\x1b[0m\n2018-04-07T23:39:50.168Z\n\n"
+        );
+    }
+
+    #[test]
+    fn color_choice() {
+        let no_color = Some(OsStr::new("1"));
+        let empty = Some(OsStr::new(""));
+
+        assert!(ColorChoice::Auto.is_enabled(true, None));
+        assert!(ColorChoice::Auto.is_enabled(true, empty));
+        assert!(!ColorChoice::Auto.is_enabled(true, no_color));
+        assert!(!ColorChoice::Auto.is_enabled(false, None));
+        assert!(ColorChoice::Always.is_enabled(false, no_color));
+        assert!(!ColorChoice::Never.is_enabled(true, None));
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn inspect_prints_components() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let mut out = Vec::new();
+
+        inspect(&mut out, &ulid).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "ULID:      01CAH7NXGRDJNE9B1NY7PQGYV7
+UUID:      0162a27a-f618-6caa-e4ac-35f1ed787b67
+timestamp: 1523144390168
+datetime:  2018-04-07T23:39:50.168Z
+random:    6caae4ac35f1ed787b67
+u128:      1841368580275787095083546476239420263
+bytes:     [01, 62, a2, 7a, f6, 18, 6c, aa, e4, ac, 35, f1, ed, 78, 7b, 67]
+
+"
+        );
+    }
+
+    #[test]
+    fn inspect_returns_no_error() {
+        let args = vec![
+            "inspect".to_string(),
+            "01CB265DSMTDS096TBTZRNTBPC".to_string(),
+            "0162a27a-f618-6caa-e4ac-35f1ed787b67".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+
+        let args = vec!["decode".to_string(), "foo".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn null_terminates_records() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let format = Format {
+            null: true,
+            ..Format::default()
+        };
+        let mut out = Vec::new();
+
+        print(&mut out, &ulid, format).unwrap();
+        print(
+            &mut out,
+            &ulid,
+            Format {
+                representation: Representation::Uuid,
+                ..format
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            out,
+            b"01CAH7NXGRDJNE9B1NY7PQGYV7\x000162a27a-f618-6caa-e4ac-35f1ed787b67\0"
+        );
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn null_terminates_verbose_records() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let format = Format {
+            verbose: true,
+            null: true,
+            ..Format::default()
+        };
+        let mut out = Vec::new();
+
+        print(&mut out, &ulid, format).unwrap();
+
+        assert_eq!(
+            out,
+            b"01CAH7NXGRDJNE9B1NY7PQGYV7\n2018-04-07T23:39:50.168Z\0"
+        );
+    }
+
+    #[cfg(not(miri))] // file system access
+    #[test]
+    fn file_candidates_are_checked() {
+        let path = std::env::temp_dir().join(format!("rusty_ulid-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "01CB265DSMTDS096TBTZRNTBPC\r\n\n  0162a27a-f618-6caa-e4ac-35f1ed787b67\n",
+        )
+        .unwrap();
+        let path = path.to_str().unwrap().to_string();
+
+        let args = vec!["--file".to_string(), path.clone()];
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+
+        let args = vec!["--file".to_string(), path.clone(), "foo".to_string()];
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_INVALID);
+
+        std::fs::remove_file(&path).unwrap();
+
+        let args = vec!["--file".to_string(), path, "foo".to_string()];
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[test]
+    fn strict_rejects_non_canonical_characters() {
+        let args = vec![
+            "--strict".to_string(),
+            "01CAH7NXGRDJNE9B1NY7PQGYVl".to_string(),
+        ];
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_INVALID);
+    }
+
+    #[test]
+    fn hyphenated_values_require_lenient() {
+        let hyphenated = "01CAH-7NXGR-DJNE9-B1NY7-PQGYV-7";
+
+        let result = main_with_args_and_return_value(vec![hyphenated.to_string()]);
+        assert_eq!(result, EXIT_INVALID);
+
+        let args = vec!["--lenient".to_string(), hyphenated.to_string()];
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn unknown_option_returns_usage_error() {
+        let args = vec![
+            "--frobnicate".to_string(),
+            "01CB265DSMTDS096TBTZRNTBPC".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn bench_returns_no_error() {
+        let args = vec![
+            "bench".to_string(),
+            "--duration".to_string(),
+            "10ms".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn every_with_count_returns_no_error() {
+        let args = vec![
+            "--every".to_string(),
+            "1ms".to_string(),
+            "-n".to_string(),
+            "3".to_string(),
+        ];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn every_zero_returns_usage_error() {
+        let args = vec!["--every".to_string(), "0s".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, EXIT_USAGE);
+    }
+
+    #[test]
+    fn parse_duration_test_cases() {
+        assert_eq!(parse_duration("500ms"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_duration("5s"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_duration("5"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_duration("2m"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Some(Duration::from_secs(3600)));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("s"), None);
+        assert_eq!(parse_duration("5 s"), None);
+        assert_eq!(parse_duration("5d"), None);
+        assert_eq!(parse_duration("-5s"), None);
+    }
+
+    #[test]
+    fn version_short_returns_no_error() {
+        let args = vec!["-V".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn version_long_returns_no_error() {
+        let args = vec!["--version".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn help_short_returns_no_error() {
+        let args = vec!["-h".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn help_long_returns_no_error() {
+        let args = vec!["--help".to_string()];
+
+        let result = main_with_args_and_return_value(args);
+        assert_eq!(result, 0);
+    }
+}
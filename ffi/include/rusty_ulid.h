@@ -0,0 +1,60 @@
+/*
+ * C API for rusty_ulid.
+ *
+ * ULIDs are passed as 16 bytes in big-endian byte order, strings are passed as
+ * NUL-terminated ASCII. All functions return one of the RUSTY_ULID_* status codes.
+ */
+
+#ifndef RUSTY_ULID_H
+#define RUSTY_ULID_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The function succeeded. */
+#define RUSTY_ULID_OK 0
+/* A pointer argument was NULL. */
+#define RUSTY_ULID_ERROR_NULL_POINTER (-1)
+/* The string to parse does not have exactly 26 characters. */
+#define RUSTY_ULID_ERROR_INVALID_LENGTH (-2)
+/* The string to parse contains an invalid character. */
+#define RUSTY_ULID_ERROR_INVALID_CHAR (-3)
+/* The string to parse exceeds the maximum ULID value. */
+#define RUSTY_ULID_ERROR_DATA_TYPE_OVERFLOW (-4)
+
+/* Length of a ULID string buffer, including the terminating NUL. */
+#define RUSTY_ULID_STRING_SIZE 27
+
+/*
+ * Writes the 16 bytes of a new ULID to out.
+ *
+ * Returns RUSTY_ULID_OK or RUSTY_ULID_ERROR_NULL_POINTER.
+ */
+int rusty_ulid_generate(uint8_t *out);
+
+/*
+ * Writes the NUL-terminated string representation of the 16 bytes to out,
+ * which must hold RUSTY_ULID_STRING_SIZE characters.
+ *
+ * Returns RUSTY_ULID_OK or RUSTY_ULID_ERROR_NULL_POINTER.
+ */
+int rusty_ulid_to_string(const uint8_t *bytes, char *out);
+
+/*
+ * Parses the NUL-terminated ULID string and writes its 16 bytes to out.
+ * out is left untouched on error.
+ *
+ * Returns RUSTY_ULID_OK, RUSTY_ULID_ERROR_NULL_POINTER,
+ * RUSTY_ULID_ERROR_INVALID_LENGTH, RUSTY_ULID_ERROR_INVALID_CHAR or
+ * RUSTY_ULID_ERROR_DATA_TYPE_OVERFLOW.
+ */
+int rusty_ulid_parse(const char *string, uint8_t *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* RUSTY_ULID_H */
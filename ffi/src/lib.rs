@@ -0,0 +1,252 @@
+/*
+ * The MIT License (MIT)
+ * Copyright (c) 2018-2023 Joern Huxhorn
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the “Software”), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+/*
+ * Copyright 2018-2023 Joern Huxhorn
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+#![deny(missing_docs, unsafe_op_in_unsafe_fn)]
+
+//! C API for [rusty_ulid](https://docs.rs/rusty_ulid).
+//!
+//! This crate builds a `cdylib` and a `staticlib` exporting `extern "C"` functions
+//! for generating, formatting and parsing ULIDs. The matching declarations are
+//! contained in `include/rusty_ulid.h`.
+//!
+//! ULIDs are passed as 16 bytes in big-endian byte order, strings are passed as
+//! NUL-terminated ASCII.
+//!
+//! All functions return one of the `RUSTY_ULID_*` status codes.
+//!
+//! The `uniffi` feature additionally exports the [UniFFI](https://mozilla.github.io/uniffi-rs/)
+//! scaffolding for generating Kotlin and Swift bindings.
+
+use rusty_ulid::{DecodingError, Ulid};
+use std::ffi::{c_char, c_int, CStr};
+
+#[cfg(feature = "uniffi")]
+mod uniffi_;
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+/// The function succeeded.
+pub const RUSTY_ULID_OK: c_int = 0;
+/// A pointer argument was `NULL`.
+pub const RUSTY_ULID_ERROR_NULL_POINTER: c_int = -1;
+/// The string to parse does not have exactly 26 characters.
+pub const RUSTY_ULID_ERROR_INVALID_LENGTH: c_int = -2;
+/// The string to parse contains an invalid character.
+pub const RUSTY_ULID_ERROR_INVALID_CHAR: c_int = -3;
+/// The string to parse exceeds the maximum ULID value.
+pub const RUSTY_ULID_ERROR_DATA_TYPE_OVERFLOW: c_int = -4;
+
+/// Length of a ULID string buffer, including the terminating NUL.
+pub const RUSTY_ULID_STRING_SIZE: usize = 27;
+
+fn error_code(error: DecodingError) -> c_int {
+    match error {
+        DecodingError::InvalidLength { .. } => RUSTY_ULID_ERROR_INVALID_LENGTH,
+        DecodingError::DataTypeOverflow => RUSTY_ULID_ERROR_DATA_TYPE_OVERFLOW,
+        // any other error is caused by the characters of the string
+        _ => RUSTY_ULID_ERROR_INVALID_CHAR,
+    }
+}
+
+/// Writes the 16 bytes of a new ULID to `out`.
+///
+/// Returns `RUSTY_ULID_OK` or `RUSTY_ULID_ERROR_NULL_POINTER`.
+///
+/// # Safety
+///
+/// `out` must be `NULL` or valid for writing 16 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_ulid_generate(out: *mut u8) -> c_int {
+    // SAFETY: the caller guarantees that out is NULL or valid for 16 bytes
+    let Some(out) = (unsafe { out.cast::<[u8; 16]>().as_mut() }) else {
+        return RUSTY_ULID_ERROR_NULL_POINTER;
+    };
+    *out = Ulid::generate().into();
+    RUSTY_ULID_OK
+}
+
+/// Writes the NUL-terminated string representation of the 16 `bytes` to `out`.
+///
+/// Returns `RUSTY_ULID_OK` or `RUSTY_ULID_ERROR_NULL_POINTER`.
+///
+/// # Safety
+///
+/// `bytes` must be `NULL` or valid for reading 16 bytes. `out` must be `NULL` or
+/// valid for writing `RUSTY_ULID_STRING_SIZE` (27) bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_ulid_to_string(bytes: *const u8, out: *mut c_char) -> c_int {
+    // SAFETY: the caller guarantees that bytes is NULL or valid for 16 bytes
+    let Some(bytes) = (unsafe { bytes.cast::<[u8; 16]>().as_ref() }) else {
+        return RUSTY_ULID_ERROR_NULL_POINTER;
+    };
+    // SAFETY: the caller guarantees that out is NULL or valid for 27 bytes
+    let Some(out) = (unsafe { out.cast::<[u8; RUSTY_ULID_STRING_SIZE]>().as_mut() }) else {
+        return RUSTY_ULID_ERROR_NULL_POINTER;
+    };
+    let string = Ulid::from(*bytes).to_string();
+    out[..26].copy_from_slice(string.as_bytes());
+    out[26] = 0;
+    RUSTY_ULID_OK
+}
+
+/// Parses the NUL-terminated ULID string `string` and writes its 16 bytes to `out`.
+///
+/// Returns `RUSTY_ULID_OK`, `RUSTY_ULID_ERROR_NULL_POINTER`,
+/// `RUSTY_ULID_ERROR_INVALID_LENGTH`, `RUSTY_ULID_ERROR_INVALID_CHAR` or
+/// `RUSTY_ULID_ERROR_DATA_TYPE_OVERFLOW`. `out` is left untouched on error.
+///
+/// # Safety
+///
+/// `string` must be `NULL` or a valid NUL-terminated string. `out` must be `NULL`
+/// or valid for writing 16 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rusty_ulid_parse(string: *const c_char, out: *mut u8) -> c_int {
+    if string.is_null() {
+        return RUSTY_ULID_ERROR_NULL_POINTER;
+    }
+    // SAFETY: the caller guarantees that out is NULL or valid for 16 bytes
+    let Some(out) = (unsafe { out.cast::<[u8; 16]>().as_mut() }) else {
+        return RUSTY_ULID_ERROR_NULL_POINTER;
+    };
+    // SAFETY: the caller guarantees that string is a valid NUL-terminated string
+    let string = unsafe { CStr::from_ptr(string) };
+    let Ok(string) = string.to_str() else {
+        return RUSTY_ULID_ERROR_INVALID_CHAR;
+    };
+    match string.parse::<Ulid>() {
+        Ok(ulid) => {
+            *out = ulid.into();
+            RUSTY_ULID_OK
+        }
+        Err(error) => error_code(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    const ULID_STRING: &[u8] = b"01CAH7NXGRDJNE9B1NY7PQGYV7\0";
+    const ULID_BYTES: [u8; 16] = [
+        0x01, 0x62, 0xA2, 0x7A, 0xF6, 0x18, 0x6C, 0xAA, 0xE4, 0xAC, 0x35, 0xF1, 0xED, 0x78, 0x7B,
+        0x67,
+    ];
+
+    fn c_str(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn generate() {
+        let mut bytes = [0; 16];
+
+        assert_eq!(
+            unsafe { rusty_ulid_generate(bytes.as_mut_ptr()) },
+            RUSTY_ULID_OK
+        );
+        assert_ne!(Ulid::from(bytes).timestamp(), 0);
+        assert_eq!(
+            unsafe { rusty_ulid_generate(ptr::null_mut()) },
+            RUSTY_ULID_ERROR_NULL_POINTER
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        let mut string = [1 as c_char; RUSTY_ULID_STRING_SIZE];
+
+        assert_eq!(
+            unsafe { rusty_ulid_to_string(ULID_BYTES.as_ptr(), string.as_mut_ptr()) },
+            RUSTY_ULID_OK
+        );
+        assert_eq!(
+            unsafe { CStr::from_ptr(string.as_ptr()) },
+            c_str(ULID_STRING)
+        );
+        assert_eq!(
+            unsafe { rusty_ulid_to_string(ptr::null(), string.as_mut_ptr()) },
+            RUSTY_ULID_ERROR_NULL_POINTER
+        );
+        assert_eq!(
+            unsafe { rusty_ulid_to_string(ULID_BYTES.as_ptr(), ptr::null_mut()) },
+            RUSTY_ULID_ERROR_NULL_POINTER
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let mut bytes = [0; 16];
+
+        assert_eq!(
+            unsafe { rusty_ulid_parse(c_str(ULID_STRING).as_ptr(), bytes.as_mut_ptr()) },
+            RUSTY_ULID_OK
+        );
+        assert_eq!(bytes, ULID_BYTES);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let mut bytes = [0; 16];
+
+        let mut parse =
+            |string: &CStr| unsafe { rusty_ulid_parse(string.as_ptr(), bytes.as_mut_ptr()) };
+        assert_eq!(
+            parse(c_str(b"01CAH7NXGRDJNE9B1NY7PQGYV\0")),
+            RUSTY_ULID_ERROR_INVALID_LENGTH
+        );
+        assert_eq!(
+            parse(c_str(b"01CAH7NXGRDJNE9B1NY7PQGYVU\0")),
+            RUSTY_ULID_ERROR_INVALID_CHAR
+        );
+        assert_eq!(
+            parse(c_str(b"81CAH7NXGRDJNE9B1NY7PQGYV7\0")),
+            RUSTY_ULID_ERROR_DATA_TYPE_OVERFLOW
+        );
+        assert_eq!(bytes, [0; 16]);
+
+        assert_eq!(
+            unsafe { rusty_ulid_parse(ptr::null(), bytes.as_mut_ptr()) },
+            RUSTY_ULID_ERROR_NULL_POINTER
+        );
+        assert_eq!(
+            unsafe { rusty_ulid_parse(c_str(ULID_STRING).as_ptr(), ptr::null_mut()) },
+            RUSTY_ULID_ERROR_NULL_POINTER
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! [UniFFI](https://mozilla.github.io/uniffi-rs/) bindings for Kotlin and Swift.
+//!
+//! Generate the bindings from the compiled library, e.g.
+//!
+//! ```text
+//! cargo build -p rusty_ulid-ffi --features uniffi
+//! uniffi-bindgen generate --library target/debug/librusty_ulid_ffi.so --language kotlin --out-dir out
+//! ```
+//!
+//! ULIDs are passed as their canonical 26 character string representation.
+
+use rusty_ulid::{DecodingError, MonotonicUlids, Ulid};
+use std::fmt;
+use std::sync::Mutex;
+
+/// Error returned if a string is not a valid ULID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Error)]
+pub enum UlidError {
+    /// The string does not have exactly 26 characters.
+    InvalidLength,
+    /// The string contains an invalid character.
+    InvalidChar,
+    /// The string exceeds the maximum ULID value.
+    DataTypeOverflow,
+}
+
+impl std::error::Error for UlidError {}
+
+impl fmt::Display for UlidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "invalid length"),
+            Self::InvalidChar => write!(f, "invalid character"),
+            Self::DataTypeOverflow => write!(f, "data type overflow"),
+        }
+    }
+}
+
+impl From<DecodingError> for UlidError {
+    fn from(error: DecodingError) -> Self {
+        match error {
+            DecodingError::InvalidLength { .. } => Self::InvalidLength,
+            DecodingError::DataTypeOverflow => Self::DataTypeOverflow,
+            // any other error is caused by the characters of the string
+            _ => Self::InvalidChar,
+        }
+    }
+}
+
+/// Returns a new ULID string.
+#[uniffi::export]
+pub fn generate() -> String {
+    Ulid::generate().to_string()
+}
+
+/// Returns the next monotonic ULID string for the given `previous` ULID string.
+#[uniffi::export]
+pub fn next_monotonic(previous: &str) -> Result<String, UlidError> {
+    Ok(Ulid::next_monotonic(previous.parse()?).to_string())
+}
+
+/// Parses the given ULID string and returns its 16 bytes in big-endian byte order.
+#[uniffi::export]
+pub fn parse(ulid: &str) -> Result<Vec<u8>, UlidError> {
+    let bytes: [u8; 16] = ulid.parse::<Ulid>()?.into();
+    Ok(bytes.to_vec())
+}
+
+/// Returns the timestamp of the given ULID string in milliseconds since the UNIX epoch.
+#[uniffi::export]
+pub fn timestamp(ulid: &str) -> Result<u64, UlidError> {
+    Ok(ulid.parse::<Ulid>()?.timestamp())
+}
+
+/// Thread-safe generator of strictly monotonic ULIDs.
+#[derive(Debug, uniffi::Object)]
+pub struct MonotonicGenerator {
+    ulids: Mutex<MonotonicUlids>,
+}
+
+#[uniffi::export]
+impl MonotonicGenerator {
+    /// Creates a new generator.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {
+            ulids: Mutex::new(MonotonicUlids::new()),
+        }
+    }
+
+    /// Returns the next monotonic ULID string.
+    ///
+    /// Every ULID is strictly larger than the previous one returned by this
+    /// generator, see [`MonotonicUlids`].
+    pub fn generate(&self) -> String {
+        self.ulids
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .next()
+            .expect("MonotonicUlids is endless")
+            .to_string()
+    }
+}
+
+impl Default for MonotonicGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_timestamp() {
+        assert_eq!(
+            parse("01CAH7NXGRDJNE9B1NY7PQGYV7").unwrap(),
+            [
+                0x01, 0x62, 0xA2, 0x7A, 0xF6, 0x18, 0x6C, 0xAA, 0xE4, 0xAC, 0x35, 0xF1, 0xED, 0x78,
+                0x7B, 0x67
+            ]
+        );
+        assert_eq!(
+            timestamp("01CAH7NXGRDJNE9B1NY7PQGYV7"),
+            Ok(1_523_144_390_168)
+        );
+        assert_eq!(
+            timestamp("01CAH7NXGRDJNE9B1NY7PQGYV"),
+            Err(UlidError::InvalidLength)
+        );
+        assert_eq!(
+            parse("01CAH7NXGRDJNE9B1NY7PQGYVU"),
+            Err(UlidError::InvalidChar)
+        );
+        assert_eq!(
+            next_monotonic("81CAH7NXGRDJNE9B1NY7PQGYV7"),
+            Err(UlidError::DataTypeOverflow)
+        );
+    }
+
+    #[test]
+    fn monotonic_generator() {
+        let generator = MonotonicGenerator::new();
+
+        let first = generator.generate();
+        let second = generator.generate();
+
+        assert!(first < second);
+    }
+}
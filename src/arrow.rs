@@ -0,0 +1,164 @@
+//! Conversions between ULIDs and [Apache Arrow](https://docs.rs/arrow) arrays.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `arrow` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["arrow"]
+//! ```
+//!
+//! # Usage
+//!
+//! ULIDs are stored as `FixedSizeBinary(16)` column containing the 16 bytes of
+//! every ULID in big-endian byte order, so the column sorts like the ULIDs
+//! themselves:
+//!
+//! ```rust
+//! use arrow_array::Array;
+//! use rusty_ulid::{arrow, Ulid};
+//!
+//! let ulids = [Ulid::from(1), Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67)];
+//!
+//! let array = arrow::to_fixed_size_binary_array(&ulids);
+//! assert_eq!(array.value_length(), 16);
+//! assert_eq!(array.len(), 2);
+//!
+//! let converted = arrow::from_fixed_size_binary_array(&array)?;
+//! assert_eq!(converted, [Some(ulids[0]), Some(ulids[1])]);
+//! # Ok::<(), rusty_ulid::DecodingError>(())
+//! ```
+//!
+//! Datasets that store the canonical string instead use a `Utf8` column:
+//!
+//! ```rust
+//! use rusty_ulid::{arrow, Ulid};
+//!
+//! let ulids = [Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67)];
+//!
+//! let array = arrow::to_string_array(&ulids);
+//! assert_eq!(array.value(0), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+//!
+//! assert_eq!(arrow::from_string_array(&array)?, [Some(ulids[0])]);
+//! # Ok::<(), rusty_ulid::DecodingError>(())
+//! ```
+//!
+//! Null values of an array are returned as `None`.
+
+use crate::{DecodingError, Ulid};
+use arrow_array::builder::{FixedSizeBinaryBuilder, StringBuilder};
+use arrow_array::{FixedSizeBinaryArray, StringArray};
+
+/// Returns a `FixedSizeBinary(16)` array containing the bytes of `ulids`.
+#[must_use]
+pub fn to_fixed_size_binary_array(ulids: &[Ulid]) -> FixedSizeBinaryArray {
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(ulids.len(), 16);
+    for ulid in ulids {
+        builder
+            .append_value(ulid.as_bytes())
+            .expect("ULIDs have 16 bytes");
+    }
+
+    builder.finish()
+}
+
+/// Returns the ULIDs of a `FixedSizeBinary(16)` array, `None` for null values.
+///
+/// # Errors
+///
+/// Returns `InvalidLength` if the values of `array` don't have 16 bytes.
+pub fn from_fixed_size_binary_array(
+    array: &FixedSizeBinaryArray,
+) -> Result<Vec<Option<Ulid>>, DecodingError> {
+    let length = array.value_length() as usize;
+    if length != 16 {
+        return Err(DecodingError::InvalidLength {
+            expected: 16,
+            actual: length,
+        });
+    }
+
+    array
+        .iter()
+        .map(|value| value.map(Ulid::try_from).transpose())
+        .collect()
+}
+
+/// Returns a `Utf8` array containing the canonical strings of `ulids`.
+#[must_use]
+pub fn to_string_array(ulids: &[Ulid]) -> StringArray {
+    let mut builder = StringBuilder::with_capacity(ulids.len(), ulids.len() * 26);
+    for ulid in ulids {
+        ulid.fmt_to(&mut builder)
+            .expect("writing to a StringBuilder never fails");
+        builder.append_value("");
+    }
+
+    builder.finish()
+}
+
+/// Parses the ULID strings of a `Utf8` array, `None` for null values.
+///
+/// # Errors
+///
+/// Returns the `DecodingError` of the first value that isn't a valid ULID
+/// string, see `FromStr` of [`Ulid`].
+pub fn from_string_array(array: &StringArray) -> Result<Vec<Option<Ulid>>, DecodingError> {
+    array
+        .iter()
+        .map(|value| value.map(str::parse).transpose())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+
+    #[test]
+    fn nulls_are_none() {
+        let bytes = Ulid::from(1).to_bytes();
+        let array = FixedSizeBinaryArray::from(vec![Some(&bytes[..]), None]);
+
+        assert_eq!(
+            from_fixed_size_binary_array(&array),
+            Ok(vec![Some(Ulid::from(1)), None])
+        );
+
+        let array = StringArray::from(vec![None, Some("00000000000000000000000001")]);
+        assert_eq!(
+            from_string_array(&array),
+            Ok(vec![None, Some(Ulid::from(1))])
+        );
+    }
+
+    #[test]
+    fn empty_slices_convert() {
+        assert_eq!(to_fixed_size_binary_array(&[]).len(), 0);
+        assert_eq!(to_string_array(&[]).len(), 0);
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        let array = FixedSizeBinaryArray::from(vec![Some(&[0_u8; 8][..])]);
+        assert_eq!(
+            from_fixed_size_binary_array(&array),
+            Err(DecodingError::InvalidLength {
+                expected: 16,
+                actual: 8
+            })
+        );
+
+        let array = StringArray::from(vec!["01CAH7NXGRDJNE9B1NY7PQGYVU"]);
+        assert_eq!(
+            from_string_array(&array),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25
+            })
+        );
+    }
+}
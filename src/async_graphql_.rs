@@ -0,0 +1,109 @@
+//! [async-graphql](https://docs.rs/async-graphql) scalar support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `async-graphql` feature is enabled.
+//! Enable it in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["async-graphql"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`ScalarType`] as the `Ulid` scalar, using the canonical
+//! 26 character string representation:
+//!
+//! ```rust
+//! use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+//! use rusty_ulid::Ulid;
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn timestamp(&self, id: Ulid) -> u64 {
+//!         id.timestamp()
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! assert!(schema.sdl().contains("scalar Ulid"));
+//! ```
+
+use crate::Ulid;
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A [ULID](https://github.com/ulid/spec) in its canonical 26 character string representation.
+#[Scalar(name = "Ulid", specified_by_url = "https://github.com/ulid/spec")]
+impl ScalarType for Ulid {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(string) => Ok(string.parse()?),
+            other => Err(InputValueError::expected_type(other)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(string) if string.parse::<Self>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn echo(&self, id: Ulid) -> Ulid {
+            id
+        }
+    }
+
+    async fn execute(query: &str) -> async_graphql::Response {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+            .execute(query)
+            .await
+    }
+
+    #[tokio::test]
+    async fn round_trip() {
+        let response = execute(r#"{ echo(id: "01ARZ3NDEKTSV4RRFFQ69G5FAV") }"#).await;
+
+        assert!(response.errors.is_empty());
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "echo": "01ARZ3NDEKTSV4RRFFQ69G5FAV" })
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_ulid() {
+        let response = execute(r#"{ echo(id: "01ARZ3NDEKTSV4RRFFQ69G5FA") }"#).await;
+
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_non_string() {
+        let response = execute("{ echo(id: 42) }").await;
+
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_and_to_value() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        assert_eq!(<Ulid as ScalarType>::parse(ulid.to_value()).unwrap(), ulid);
+    }
+}
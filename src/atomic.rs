@@ -0,0 +1,153 @@
+//! Monotonic generator based on the 128-bit atomics of `portable-atomic`.
+
+use crate::Ulid;
+use portable_atomic::{AtomicU128, Ordering};
+
+/// Generator of strictly increasing ULIDs that can be shared between threads
+/// without a `Mutex`.
+///
+/// The previous ULID is kept in a 128-bit atomic of the
+/// [portable-atomic](https://docs.rs/portable-atomic) crate. It's only
+/// lock-free on targets with native 128-bit atomics, e.g. `x86_64` with
+/// `cmpxchg16b` or `aarch64`, see `portable_atomic::AtomicU128::is_lock_free`.
+/// On other targets, `portable-atomic` falls back to a global lock.
+///
+/// The generator doesn't require `std`. Targets without any atomic
+/// compare-and-swap, like Cortex-M0 (`thumbv6m-none-eabi`) or RISC-V without
+/// the A extension (`riscv32imc-unknown-none-elf`), additionally need the
+/// `critical-section` feature of `portable-atomic`, which then guards the
+/// atomic with a critical section.
+///
+/// Every ULID is strictly larger than the previous one of the same generator,
+/// regardless of the caller. If the ULID of the same millisecond can't be
+/// incremented, the next ULID borrows the following millisecond. If the clock
+/// moves backwards, the previous ULID is incremented instead.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::AtomicUlidGenerator;
+///
+/// static GENERATOR: AtomicUlidGenerator = AtomicUlidGenerator::new();
+///
+/// let first = GENERATOR.next_ulid(1_523_144_390_168, [0xA5; 10]);
+/// let second = GENERATOR.next_ulid(1_523_144_390_168, [0x5A; 10]);
+///
+/// assert!(first < second);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicUlidGenerator {
+    previous: AtomicU128,
+}
+
+impl AtomicUlidGenerator {
+    /// Creates a new generator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            previous: AtomicU128::new(0),
+        }
+    }
+
+    /// Returns the next ULID for `timestamp`, using the 80 bits of `random` if
+    /// a new millisecond starts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+    pub fn next_ulid(&self, timestamp: u64, random: [u8; 10]) -> Ulid {
+        self.next(timestamp, Ulid::split_random_bits(random))
+    }
+
+    /// Returns the next ULID for the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::AtomicUlidGenerator;
+    ///
+    /// let generator = AtomicUlidGenerator::new();
+    ///
+    /// let first = generator.generate();
+    /// let second = generator.generate();
+    ///
+    /// assert!(first < second);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    pub fn generate(&self) -> Ulid {
+        self.next(crate::unix_epoch_ms(), crate::random_bits())
+    }
+
+    fn next(&self, timestamp: u64, random: (u16, u64)) -> Ulid {
+        let next = |previous: u128| {
+            // the initial value of 0 is smaller than every ULID that can follow
+            Ulid::next_strictly_increasing(Some(Ulid::from(previous)), timestamp, || random)
+        };
+
+        let previous = self
+            .previous
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |previous| {
+                Some(next(previous).into())
+            })
+            .expect("the update always succeeds");
+
+        next(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn first_ulid_uses_random_bits() {
+        let generator = AtomicUlidGenerator::new();
+
+        assert_eq!(
+            generator.next_ulid(1, [0xAB, 0xCD, 0, 0, 0, 0, 0, 0, 0, 0x2A]),
+            Ulid::from(0x0000_0000_0001_ABCD_0000_0000_0000_002A)
+        );
+        assert_eq!(
+            generator.next_ulid(0, [0; 10]),
+            Ulid::from(0x0000_0000_0001_ABCD_0000_0000_0000_002B)
+        );
+    }
+
+    #[test]
+    fn shared_generator_is_strictly_increasing_across_threads() {
+        let generator = Arc::new(AtomicUlidGenerator::new());
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                std::thread::spawn(move || {
+                    (0..1000)
+                        .map(|_| generator.next_ulid(1_523_144_390_168, [0xFF; 10]))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut ulids: Vec<Ulid> = handles
+            .into_iter()
+            .flat_map(|handle| {
+                let ulids = handle.join().unwrap();
+                assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+                ulids
+            })
+            .collect();
+        let count = ulids.len();
+        ulids.sort();
+        ulids.dedup();
+
+        assert_eq!(ulids.len(), count);
+    }
+}
@@ -0,0 +1,121 @@
+//! [axum](https://docs.rs/axum) path extractor with a typed rejection.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `axum` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["axum"]
+//! ```
+//!
+//! # Usage
+//!
+//! [`UlidParam`] extracts a `Ulid` from a route with a single path parameter.
+//! Invalid ULIDs are rejected with [`UlidRejection`], which responds with
+//! `400 Bad Request` by default:
+//!
+//! ```rust
+//! use axum::{routing::get, Router};
+//! use rusty_ulid::axum::UlidParam;
+//!
+//! async fn user(UlidParam(id): UlidParam) -> String {
+//!     format!("We found: {id}")
+//! }
+//!
+//! let app: Router = Router::new().route("/users/{id}", get(user));
+//! ```
+//!
+//! Extract `Result<UlidParam, UlidRejection>` to customize the error response:
+//!
+//! ```rust
+//! use axum::http::StatusCode;
+//! use rusty_ulid::axum::{UlidParam, UlidRejection};
+//!
+//! async fn user(
+//!     id: Result<UlidParam, UlidRejection>,
+//! ) -> Result<String, (StatusCode, String)> {
+//!     match id {
+//!         Ok(UlidParam(id)) => Ok(format!("We found: {id}")),
+//!         Err(rejection) => Err((StatusCode::NOT_FOUND, rejection.to_string())),
+//!     }
+//! }
+//! ```
+
+use crate::{DecodingError, Ulid};
+use axum::extract::rejection::PathRejection;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::error::Error;
+use std::fmt;
+
+/// Extractor parsing the single path parameter of a route as `Ulid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UlidParam(pub Ulid);
+
+/// Rejection used for [`UlidParam`].
+#[derive(Debug)]
+#[allow(variant_size_differences)]
+pub enum UlidRejection {
+    /// The path parameter could not be extracted.
+    Path(PathRejection),
+
+    /// The path parameter is not a valid ULID.
+    Decoding(DecodingError),
+}
+
+impl UlidRejection {
+    /// Returns the `DecodingError` if the path parameter is not a valid ULID.
+    #[must_use]
+    pub fn decoding_error(&self) -> Option<DecodingError> {
+        match self {
+            Self::Decoding(error) => Some(*error),
+            Self::Path(_) => None,
+        }
+    }
+}
+
+impl Error for UlidRejection {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Path(rejection) => Some(rejection),
+            Self::Decoding(error) => Some(error),
+        }
+    }
+}
+
+impl fmt::Display for UlidRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Self::Path(rejection) => write!(f, "{rejection}"),
+            Self::Decoding(error) => write!(f, "invalid ULID: {error}"),
+        }
+    }
+}
+
+impl IntoResponse for UlidRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Path(rejection) => rejection.into_response(),
+            Self::Decoding(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for UlidParam
+where
+    S: Send + Sync,
+{
+    type Rejection = UlidRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(param) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(UlidRejection::Path)?;
+        param.parse().map(Self).map_err(UlidRejection::Decoding)
+    }
+}
@@ -0,0 +1,100 @@
+//! Native [bincode](https://docs.rs/bincode) 2 encoding support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `bincode` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["bincode"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Encode`], [`Decode`] and [`BorrowDecode`] without going
+//! through `serde`. A `Ulid` is always encoded as exactly 16 bytes in big-endian
+//! byte order, regardless of the configured integer encoding or endianness:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//! let config = bincode::config::standard();
+//!
+//! let bytes = bincode::encode_to_vec(ulid, config).unwrap();
+//! assert_eq!(bytes, <[u8; 16]>::from(ulid));
+//!
+//! let (decoded, len): (Ulid, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+//! assert_eq!(decoded, ulid);
+//! assert_eq!(len, 16);
+//! ```
+
+use crate::Ulid;
+use bincode::de::read::Reader;
+use bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use bincode::enc::write::Writer;
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{DecodeError, EncodeError};
+
+impl Encode for Ulid {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.writer().write(&<[u8; 16]>::from(*self))
+    }
+}
+
+impl<Context> Decode<Context> for Ulid {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        decoder.claim_bytes_read(16)?;
+        let mut bytes = [0; 16];
+        decoder.reader().read(&mut bytes)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for Ulid {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Self::decode(decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bincode::config;
+
+    #[test]
+    fn bincode_layout_ignores_config() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let config = config::legacy()
+            .with_little_endian()
+            .with_fixed_int_encoding();
+
+        let bytes = bincode::encode_to_vec(ulid, config).unwrap();
+
+        assert_eq!(bytes, <[u8; 16]>::from(ulid));
+    }
+
+    #[test]
+    fn bincode_borrow_decode() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let bytes = <[u8; 16]>::from(ulid);
+
+        let (decoded, _): (Ulid, usize) =
+            bincode::borrow_decode_from_slice(&bytes, config::standard()).unwrap();
+
+        assert_eq!(decoded, ulid);
+    }
+
+    #[test]
+    fn bincode_rejects_truncated_input() {
+        let bytes = [0; 15];
+
+        let result = bincode::decode_from_slice::<Ulid, _>(&bytes, config::standard());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! [Borsh](https://borsh.io) serialization support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `borsh` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["borsh"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`BorshSerialize`] and [`BorshDeserialize`] as a fixed
+//! 16-byte value using the same big-endian byte order as `<[u8; 16]>::from(ulid)`:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let bytes = borsh::to_vec(&ulid).unwrap();
+//! assert_eq!(bytes, <[u8; 16]>::from(ulid));
+//!
+//! let deserialized: Ulid = borsh::from_slice(&bytes).unwrap();
+//! assert_eq!(deserialized, ulid);
+//! ```
+
+use crate::Ulid;
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Ulid {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&<[u8; 16]>::from(*self))
+    }
+}
+
+impl BorshDeserialize for Ulid {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = [0; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self::from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borsh_rejects_truncated_input() {
+        let bytes = [0; 15];
+
+        let result = borsh::from_slice::<Ulid>(&bytes);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn borsh_rejects_trailing_bytes() {
+        let bytes = [0; 17];
+
+        let result = borsh::from_slice::<Ulid>(&bytes);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! [BSON](https://bsonspec.org) conversions for storing ULIDs in MongoDB.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `bson` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["bson"]
+//! ```
+//!
+//! # Usage
+//!
+//! A `Ulid` converts into a 16-byte [`Binary`] using the same big-endian byte
+//! order as `<[u8; 16]>::from(ulid)`. The generic subtype is used by default;
+//! [`to_uuid_binary`] uses the UUID subtype instead.
+//!
+//! ```rust
+//! use bson::{Binary, Bson};
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let binary = Binary::from(ulid);
+//! assert_eq!(binary.bytes, <[u8; 16]>::from(ulid));
+//!
+//! let bson = Bson::from(ulid);
+//! assert_eq!(Ulid::try_from(bson), Ok(ulid));
+//! ```
+//!
+//! The [`ulid_as_binary`] and [`ulid_as_uuid_binary`] modules can be used with
+//! `#[serde(with = "...")]` to store `Ulid` fields as binary instead of strings.
+
+use crate::{DecodingError, Ulid};
+use bson::spec::{BinarySubtype, ElementType};
+use bson::{Binary, Bson};
+use std::error::Error;
+use std::fmt;
+
+/// Error that can occur while converting a [`Bson`] value into a `Ulid`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(variant_size_differences)]
+pub enum BsonConversionError {
+    /// The value is neither a binary nor a string.
+    InvalidType(ElementType),
+
+    /// The binary subtype is neither generic nor UUID.
+    InvalidSubtype(BinarySubtype),
+
+    /// The binary does not contain exactly 16 bytes or the string is not a valid ULID.
+    Decoding(DecodingError),
+}
+
+impl Error for BsonConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Decoding(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BsonConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match *self {
+            Self::InvalidType(element_type) => write!(f, "invalid BSON type {element_type:?}"),
+            Self::InvalidSubtype(subtype) => write!(f, "invalid binary subtype {subtype:?}"),
+            Self::Decoding(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl From<DecodingError> for BsonConversionError {
+    fn from(error: DecodingError) -> Self {
+        Self::Decoding(error)
+    }
+}
+
+/// Returns a 16-byte [`Binary`] with the UUID subtype.
+///
+/// # Examples
+///
+/// ```
+/// use bson::spec::BinarySubtype;
+/// use rusty_ulid::Ulid;
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+///
+/// let binary = rusty_ulid::bson::to_uuid_binary(ulid);
+///
+/// assert_eq!(binary.subtype, BinarySubtype::Uuid);
+/// assert_eq!(Ulid::try_from(binary), Ok(ulid));
+/// ```
+#[must_use]
+pub fn to_uuid_binary(ulid: Ulid) -> Binary {
+    Binary {
+        subtype: BinarySubtype::Uuid,
+        bytes: <[u8; 16]>::from(ulid).to_vec(),
+    }
+}
+
+impl From<Ulid> for Binary {
+    fn from(ulid: Ulid) -> Self {
+        Self {
+            subtype: BinarySubtype::Generic,
+            bytes: <[u8; 16]>::from(ulid).to_vec(),
+        }
+    }
+}
+
+impl From<Ulid> for Bson {
+    fn from(ulid: Ulid) -> Self {
+        Self::Binary(Binary::from(ulid))
+    }
+}
+
+impl TryFrom<Binary> for Ulid {
+    type Error = BsonConversionError;
+
+    fn try_from(binary: Binary) -> Result<Self, Self::Error> {
+        match binary.subtype {
+            BinarySubtype::Generic | BinarySubtype::Uuid => Ok(Self::try_from(&binary.bytes[..])?),
+            subtype => Err(BsonConversionError::InvalidSubtype(subtype)),
+        }
+    }
+}
+
+/// Accepts a 16-byte binary with generic or UUID subtype as well as a ULID string.
+impl TryFrom<Bson> for Ulid {
+    type Error = BsonConversionError;
+
+    fn try_from(bson: Bson) -> Result<Self, Self::Error> {
+        match bson {
+            Bson::Binary(binary) => Self::try_from(binary),
+            Bson::String(string) => Ok(string.parse()?),
+            other => Err(BsonConversionError::InvalidType(other.element_type())),
+        }
+    }
+}
+
+fn deserialize_bson<'de, D>(deserializer: D) -> Result<Ulid, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let bson = <Bson as serde::Deserialize>::deserialize(deserializer)?;
+    Ulid::try_from(bson).map_err(serde::de::Error::custom)
+}
+
+/// Serializes a `Ulid` as 16-byte BSON binary with the generic subtype.
+///
+/// Deserialization accepts binary with generic or UUID subtype as well as ULID strings.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "rusty_ulid::bson::ulid_as_binary")]
+///     id: Ulid,
+/// }
+///
+/// let event = Event {
+///     id: Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67),
+/// };
+///
+/// let document = bson::to_document(&event).unwrap();
+/// assert_eq!(document.get("id"), Some(&bson::Bson::from(event.id)));
+///
+/// let deserialized: Event = bson::from_document(document).unwrap();
+/// assert_eq!(deserialized, event);
+/// ```
+pub mod ulid_as_binary {
+    use crate::Ulid;
+    use bson::Binary;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Serializes a `Ulid` as BSON binary with the generic subtype.
+    pub fn serialize<S>(ulid: &Ulid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Binary::from(*ulid).serialize(serializer)
+    }
+
+    /// Deserializes a `Ulid` from BSON binary or a ULID string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Ulid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize_bson(deserializer)
+    }
+}
+
+/// Serializes a `Ulid` as 16-byte BSON binary with the UUID subtype.
+///
+/// Deserialization accepts binary with generic or UUID subtype as well as ULID strings.
+///
+/// # Examples
+///
+/// ```
+/// use bson::spec::BinarySubtype;
+/// use bson::Bson;
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "rusty_ulid::bson::ulid_as_uuid_binary")]
+///     id: Ulid,
+/// }
+///
+/// let event = Event {
+///     id: Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67),
+/// };
+///
+/// let document = bson::to_document(&event).unwrap();
+/// match document.get("id") {
+///     Some(Bson::Binary(binary)) => assert_eq!(binary.subtype, BinarySubtype::Uuid),
+///     other => panic!("unexpected {other:?}"),
+/// }
+///
+/// let deserialized: Event = bson::from_document(document).unwrap();
+/// assert_eq!(deserialized, event);
+/// ```
+pub mod ulid_as_uuid_binary {
+    use crate::Ulid;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Serializes a `Ulid` as BSON binary with the UUID subtype.
+    pub fn serialize<S>(ulid: &Ulid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::to_uuid_binary(*ulid).serialize(serializer)
+    }
+
+    /// Deserializes a `Ulid` from BSON binary or a ULID string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Ulid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize_bson(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_bson_string() {
+        let bson = Bson::String(String::from("01CAH7NXGRDJNE9B1NY7PQGYV7"));
+
+        assert_eq!(
+            Ulid::try_from(bson),
+            Ok(Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67))
+        );
+    }
+
+    #[test]
+    fn try_from_bson_rejects_invalid_values() {
+        assert_eq!(
+            Ulid::try_from(Bson::Int32(42)),
+            Err(BsonConversionError::InvalidType(ElementType::Int32))
+        );
+        assert_eq!(
+            Ulid::try_from(Bson::String(String::from("01CAH7NXGRDJNE9B1NY7PQGYV"))),
+            Err(BsonConversionError::Decoding(
+                DecodingError::InvalidLength {
+                    expected: 26,
+                    actual: 25
+                }
+            ))
+        );
+        assert_eq!(
+            Ulid::try_from(Bson::Binary(Binary {
+                subtype: BinarySubtype::Md5,
+                bytes: vec![0; 16],
+            })),
+            Err(BsonConversionError::InvalidSubtype(BinarySubtype::Md5))
+        );
+        assert_eq!(
+            Ulid::try_from(Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: vec![0; 15],
+            })),
+            Err(BsonConversionError::Decoding(
+                DecodingError::InvalidLength {
+                    expected: 16,
+                    actual: 15
+                }
+            ))
+        );
+    }
+}
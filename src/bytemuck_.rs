@@ -0,0 +1,68 @@
+//! [bytemuck](https://docs.rs/bytemuck) support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `bytemuck` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["bytemuck"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Pod`](bytemuck::Pod) and [`Zeroable`](bytemuck::Zeroable),
+//! so slices of ULIDs can be reinterpreted as byte slices and vice versa without
+//! copying:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let ulids = vec![Ulid::from(1), Ulid::from(2)];
+//!
+//! let bytes: &[u8] = bytemuck::cast_slice(&ulids);
+//! assert_eq!(bytes.len(), 32);
+//!
+//! let restored: &[Ulid] = bytemuck::cast_slice(bytes);
+//! assert_eq!(restored, &ulids[..]);
+//! ```
+//!
+//! The in-memory representation consists of the 16 bytes of the ULID in
+//! big-endian byte order, the same as `<[u8; 16]>::from(ulid)`. It is therefore
+//! portable between machines of different endianness and sorts like the ULIDs
+//! themselves.
+
+#[cfg(test)]
+mod tests {
+    use crate::Ulid;
+
+    #[test]
+    fn zeroed_is_nil() {
+        let ulid: Ulid = bytemuck::Zeroable::zeroed();
+
+        assert_eq!(ulid, Ulid::from(0));
+    }
+
+    #[test]
+    fn cast_matches_big_endian_bytes() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        let bytes: [u8; 16] = bytemuck::cast(ulid);
+
+        assert_eq!(
+            bytes,
+            0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67_u128.to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn try_cast_slice_rejects_partial_ulid() {
+        let bytes = [0u64; 3];
+
+        let result = bytemuck::try_cast_slice::<u64, Ulid>(&bytes);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! Global monotonic generator guarded by a [critical section][critical-section].
+//!
+//! # Enabling
+//!
+//! This module is only available when the `critical-section` feature is enabled.
+//! Enable it in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["critical-section"]
+//! ```
+//!
+//! The feature doesn't require `std`, so it can be combined with
+//! `default-features = false` on microcontrollers. The application has to
+//! provide a critical section implementation, e.g. the one of its HAL or the
+//! `std` feature of `critical-section`.
+//!
+//! # Usage
+//!
+//! The state of the generator is kept in a `static` that is only accessed
+//! within a critical section, so every ULID is strictly larger than the
+//! previous one, regardless of the caller, including interrupt handlers.
+//!
+//! Callers that obtain the time and randomness themselves, as required without
+//! `std`, pass the timestamp and the 80 random bits, e.g. of a hardware random
+//! number generator, to [`next_ulid`]:
+//!
+//! ```rust
+//! use rusty_ulid::critical_section;
+//!
+//! let first = critical_section::next_ulid(1_523_144_390_168, [0xA5; 10]);
+//! let second = critical_section::next_ulid(1_523_144_390_168, [0x5A; 10]);
+//!
+//! assert!(first < second);
+//! ```
+//!
+//! If the ULID of the same millisecond can't be incremented, the next ULID
+//! borrows the following millisecond. If the clock moves backwards, the
+//! previous ULID is incremented instead.
+//!
+//! [critical-section]: https://docs.rs/critical-section
+
+use crate::Ulid;
+use core::cell::Cell;
+use critical_section::Mutex;
+
+static PREVIOUS_ULID: Mutex<Cell<Option<Ulid>>> = Mutex::new(Cell::new(None));
+
+/// Returns the next ULID of the global generator for `timestamp`, using the
+/// 80 bits of `random` if a new millisecond starts.
+///
+/// # Panics
+///
+/// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+pub fn next_ulid(timestamp: u64, random: [u8; 10]) -> Ulid {
+    next_global(timestamp, Ulid::split_random_bits(random))
+}
+
+/// Returns the next ULID of the global generator for the current time.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::critical_section;
+///
+/// let first = critical_section::generate();
+/// let second = critical_section::generate();
+///
+/// assert!(first < second);
+/// ```
+///
+/// # Panics
+///
+/// Panics if called after `+10889-08-02T05:31:50.655Z`.
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+#[must_use]
+pub fn generate() -> Ulid {
+    next_global(crate::unix_epoch_ms(), crate::random_bits())
+}
+
+fn next_global(timestamp: u64, random: (u16, u64)) -> Ulid {
+    critical_section::with(|cs| {
+        let previous = PREVIOUS_ULID.borrow(cs);
+        let ulid = Ulid::next_strictly_increasing(previous.get(), timestamp, || random);
+        previous.set(Some(ulid));
+        ulid
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_generator_is_strictly_increasing_across_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    (0..1000)
+                        .map(|_| next_ulid(1_523_144_390_168, [0xFF; 10]))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut ulids: Vec<Ulid> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+        let count = ulids.len();
+        ulids.sort();
+        ulids.dedup();
+
+        assert_eq!(ulids.len(), count);
+    }
+}
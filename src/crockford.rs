@@ -38,39 +38,116 @@
  */
 
 #![deny(missing_docs)]
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
 
-#[rustfmt::skip]
-static ENCODING_DIGITS: [char; 32] = [
-    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K',
-    'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W', 'X',
-    'Y', 'Z',
-];
+/// The 32 symbols of the [crockford Base32][crockford] alphabet, in encoding order.
+///
+/// The symbol at index `n` encodes the 5-bit value `n`.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::ALPHABET;
+///
+/// assert_eq!(ALPHABET[0], b'0');
+/// assert_eq!(ALPHABET[31], b'Z');
+/// ```
+pub const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 
-fn resolve_u64_value_for_char(c: char) -> Result<u64, DecodingError> {
-    let index = c as usize;
+/// Returns the 5-bit value of the given [crockford Base32][crockford] symbol,
+/// or `None` if `symbol` is not part of the alphabet.
+///
+/// Upper and lower case letters are accepted,
+/// `i` and `l` will be treated as `1` and `o` will be treated as `0`.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::decode_digit;
+///
+/// assert_eq!(decode_digit(b'Z'), Some(31));
+/// assert_eq!(decode_digit(b'z'), Some(31));
+/// assert_eq!(decode_digit(b'L'), Some(1));
+/// assert_eq!(decode_digit(b'O'), Some(0));
+/// assert_eq!(decode_digit(b'U'), None);
+/// ```
+#[must_use]
+pub const fn decode_digit(symbol: u8) -> Option<u8> {
+    let index = symbol as usize;
     if index < DECODING_DIGITS.len() {
-        if let Some(u8_value) = DECODING_DIGITS[index] {
-            return Ok(u64::from(u8_value));
+        DECODING_DIGITS[index]
+    } else {
+        None
+    }
+}
+
+// Compile-time check that ALPHABET and DECODING_DIGITS agree with each other.
+const _: () = {
+    let mut value = 0;
+    while value < ALPHABET.len() {
+        let symbol = ALPHABET[value];
+        match decode_digit(symbol) {
+            Some(decoded) => assert!(decoded as usize == value),
+            None => panic!("ALPHABET symbol is not decodable"),
+        }
+        match decode_digit(symbol.to_ascii_lowercase()) {
+            Some(decoded) => assert!(decoded as usize == value),
+            None => panic!("lowercase ALPHABET symbol is not decodable"),
         }
+        value += 1;
     }
-    Err(DecodingError::InvalidChar(c))
+
+    let mut index = 0;
+    while index < DECODING_DIGITS.len() {
+        if let Some(decoded) = DECODING_DIGITS[index] {
+            assert!((decoded as usize) < ALPHABET.len());
+        }
+        index += 1;
+    }
+};
+
+fn resolve_u64_value_for_byte(input: &[u8], position: usize) -> Result<u64, DecodingError> {
+    resolve_value_for_byte(input, position).map(u64::from)
 }
 
-fn resolve_u128_value_for_char(c: char) -> Result<u128, DecodingError> {
-    let index = c as usize;
-    if index < DECODING_DIGITS.len() {
-        if let Some(u8_value) = DECODING_DIGITS[index] {
-            return Ok(u128::from(u8_value));
+fn resolve_u128_value_for_byte(input: &[u8], position: usize) -> Result<u128, DecodingError> {
+    resolve_value_for_byte(input, position).map(u128::from)
+}
+
+fn resolve_value_for_byte(input: &[u8], position: usize) -> Result<u8, DecodingError> {
+    let byte = input[position];
+    decode_digit(byte).ok_or(DecodingError::InvalidChar {
+        character: char::from(byte),
+        position,
+    })
+}
+
+/// Returns the error of decoding a string of the right length that contains at
+/// least one non-ASCII character, reporting the first offending character.
+fn non_ascii_error(input: &str) -> DecodingError {
+    for (position, character) in input.char_indices() {
+        match u8::try_from(character).ok().and_then(decode_digit) {
+            None => {
+                return DecodingError::InvalidChar {
+                    character,
+                    position,
+                }
+            }
+            Some(value) if position == 0 && value > 7 => return DecodingError::DataTypeOverflow,
+            Some(_) => {}
         }
     }
-    Err(DecodingError::InvalidChar(c))
+    unreachable!("input contains a non-ASCII character")
 }
 
 #[rustfmt::skip]
-static DECODING_DIGITS: [Option<u8>; 123] = [
+const DECODING_DIGITS: [Option<u8>; 123] = [
     // 0
     None, None, None, None, None, None, None, None,
     // 8
@@ -109,28 +186,77 @@ static DECODING_DIGITS: [Option<u8>; 123] = [
 ///
 /// [crockford]: https://crockford.com/wrmg/base32.html
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DecodingError {
     /// The length of the parsed string or given slice of bytes does not conform to requirements.
-    InvalidLength,
+    InvalidLength {
+        /// The expected length, or the maximum length if inputs of varying length are accepted.
+        expected: usize,
+        /// The length of the parsed input.
+        actual: usize,
+    },
 
     /// The parsed string contains a character that is not allowed in a
     /// [crockford Base32][crockford] string.
     ///
     /// [crockford]: https://crockford.com/wrmg/base32.html
-    InvalidChar(char),
+    InvalidChar {
+        /// The offending character.
+        character: char,
+        /// The byte position of the character in the parsed input.
+        position: usize,
+    },
 
     /// Parsing the string overflowed the result value bits.
     DataTypeOverflow,
+
+    /// The check symbol of the parsed string is a valid check symbol that
+    /// doesn't match the parsed value.
+    ChecksumMismatch {
+        /// The check symbol of the parsed value.
+        expected: char,
+        /// The check symbol contained in the parsed string.
+        actual: char,
+    },
 }
 
+#[cfg(feature = "std")]
 impl Error for DecodingError {}
 
+impl DecodingError {
+    /// Returns this error with the position of an `InvalidChar` moved by `offset`,
+    /// used if the decoded input is part of a larger string.
+    pub(crate) fn offset_position(self, offset: usize) -> Self {
+        match self {
+            Self::InvalidChar {
+                character,
+                position,
+            } => Self::InvalidChar {
+                character,
+                position: position + offset,
+            },
+            other => other,
+        }
+    }
+}
+
 impl fmt::Display for DecodingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match *self {
-            Self::InvalidLength => write!(f, "invalid length"),
-            Self::InvalidChar(c) => write!(f, "invalid character '{c}'"),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length {actual}, expected {expected}")
+            }
+            Self::InvalidChar {
+                character,
+                position,
+            } => write!(f, "invalid character '{character}' at position {position}"),
             Self::DataTypeOverflow => write!(f, "data type overflow"),
+            Self::ChecksumMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "check symbol '{actual}' doesn't match, expected '{expected}'"
+                )
+            }
         }
     }
 }
@@ -171,33 +297,53 @@ const MASK_U128: u128 = 0b11111;
 /// append_crockford_u128(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF, &mut a_string);
 /// assert_eq!(a_string, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
 /// ```
+#[cfg(feature = "std")]
 pub fn append_crockford_u128(value: u128, to_append_to: &mut String) {
-    to_append_to.push(ENCODING_DIGITS[(value >> 125) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 120) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 115) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 110) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 105) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 100) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 95) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 90) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 85) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 80) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 75) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 70) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 65) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 60) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 55) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 50) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 45) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 40) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 35) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 30) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 25) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 20) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 15) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 10) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value >> 5) & MASK_U128) as usize]);
-    to_append_to.push(ENCODING_DIGITS[(value & MASK_U128) as usize]);
+    let mut buffer = [0; 26];
+    encode_crockford_u128_into(value, &mut buffer);
+    push_ascii(&buffer, to_append_to);
+}
+
+/// Writes the [crockford Base32][crockford] representation of the `u128` into `buffer`
+/// as ASCII bytes, without any allocation.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_ulid::crockford::*;
+/// let mut buffer = [0; 26];
+/// encode_crockford_u128_into(0xFF, &mut buffer);
+/// assert_eq!(&buffer, b"0000000000000000000000007Z");
+/// ```
+pub fn encode_crockford_u128_into(value: u128, buffer: &mut [u8; 26]) {
+    buffer[0] = ALPHABET[(value >> 125) as usize];
+    buffer[1] = ALPHABET[((value >> 120) & MASK_U128) as usize];
+    buffer[2] = ALPHABET[((value >> 115) & MASK_U128) as usize];
+    buffer[3] = ALPHABET[((value >> 110) & MASK_U128) as usize];
+    buffer[4] = ALPHABET[((value >> 105) & MASK_U128) as usize];
+    buffer[5] = ALPHABET[((value >> 100) & MASK_U128) as usize];
+    buffer[6] = ALPHABET[((value >> 95) & MASK_U128) as usize];
+    buffer[7] = ALPHABET[((value >> 90) & MASK_U128) as usize];
+    buffer[8] = ALPHABET[((value >> 85) & MASK_U128) as usize];
+    buffer[9] = ALPHABET[((value >> 80) & MASK_U128) as usize];
+    buffer[10] = ALPHABET[((value >> 75) & MASK_U128) as usize];
+    buffer[11] = ALPHABET[((value >> 70) & MASK_U128) as usize];
+    buffer[12] = ALPHABET[((value >> 65) & MASK_U128) as usize];
+    buffer[13] = ALPHABET[((value >> 60) & MASK_U128) as usize];
+    buffer[14] = ALPHABET[((value >> 55) & MASK_U128) as usize];
+    buffer[15] = ALPHABET[((value >> 50) & MASK_U128) as usize];
+    buffer[16] = ALPHABET[((value >> 45) & MASK_U128) as usize];
+    buffer[17] = ALPHABET[((value >> 40) & MASK_U128) as usize];
+    buffer[18] = ALPHABET[((value >> 35) & MASK_U128) as usize];
+    buffer[19] = ALPHABET[((value >> 30) & MASK_U128) as usize];
+    buffer[20] = ALPHABET[((value >> 25) & MASK_U128) as usize];
+    buffer[21] = ALPHABET[((value >> 20) & MASK_U128) as usize];
+    buffer[22] = ALPHABET[((value >> 15) & MASK_U128) as usize];
+    buffer[23] = ALPHABET[((value >> 10) & MASK_U128) as usize];
+    buffer[24] = ALPHABET[((value >> 5) & MASK_U128) as usize];
+    buffer[25] = ALPHABET[(value & MASK_U128) as usize];
 }
 
 /// Parses the given [crockford Base32][crockford] string into a `u128`.
@@ -220,10 +366,10 @@ pub fn append_crockford_u128(value: u128, to_append_to: &mut String) {
 ///
 /// let parsed = parse_crockford_u128("00000000000000000x1iIlLoO0")?;
 ///
-/// let mut string_representation = String::new();
-/// append_crockford_u128(parsed, &mut string_representation);
+/// let mut buffer = [0; 26];
+/// encode_crockford_u128_into(parsed, &mut buffer);
 ///
-/// assert_eq!(string_representation, "00000000000000000X11111000");
+/// assert_eq!(&buffer, b"00000000000000000X11111000");
 /// # Ok::<(), rusty_ulid::DecodingError>(())
 /// ```
 ///
@@ -235,7 +381,13 @@ pub fn append_crockford_u128(value: u128, to_append_to: &mut String) {
 ///
 /// let nope = parse_crockford_u128("1234567890123456789012345");
 ///
-/// assert_eq!(Err(DecodingError::InvalidLength), nope);
+/// assert_eq!(
+///     Err(DecodingError::InvalidLength {
+///         expected: 26,
+///         actual: 25
+///     }),
+///     nope
+/// );
 /// ```
 ///
 /// ```
@@ -243,7 +395,13 @@ pub fn append_crockford_u128(value: u128, to_append_to: &mut String) {
 ///
 /// let nope = parse_crockford_u128("123456789012345678901234567");
 ///
-/// assert_eq!(Err(DecodingError::InvalidLength), nope);
+/// assert_eq!(
+///     Err(DecodingError::InvalidLength {
+///         expected: 26,
+///         actual: 27
+///     }),
+///     nope
+/// );
 /// ```
 ///
 /// Parsing 26 bytes results in `DataTypeOverflow` if the `u128` would overflow.
@@ -261,54 +419,107 @@ pub fn append_crockford_u128(value: u128, to_append_to: &mut String) {
 /// ```
 ///
 /// Parsing a string containing an invalid character results in `InvalidChar` containing
-/// the character.
+/// the character and its byte position.
 ///
 /// ```
 /// use rusty_ulid::crockford::*;
 ///
 /// let nope = parse_crockford_u128("0000000000000000000000000U");
 ///
-/// assert_eq!(Err(DecodingError::InvalidChar('U')), nope);
+/// assert_eq!(
+///     Err(DecodingError::InvalidChar {
+///         character: 'U',
+///         position: 25
+///     }),
+///     nope
+/// );
 /// ```
 pub fn parse_crockford_u128(input: &str) -> Result<u128, DecodingError> {
-    let length = input.len();
-    if length != 26 {
-        return Err(DecodingError::InvalidLength);
+    if input.len() != 26 {
+        return Err(DecodingError::InvalidLength {
+            expected: 26,
+            actual: input.len(),
+        });
+    }
+    if !input.is_ascii() {
+        return Err(non_ascii_error(input));
     }
 
-    let mut chars = input.chars();
+    parse_crockford_u128_bytes(input.as_bytes())
+}
 
-    let highest = resolve_u128_value_for_char(chars.next().unwrap())?;
+/// Parses the given [crockford Base32][crockford] ASCII bytes into a `u128`.
+///
+/// This is the byte-oriented variant of [`parse_crockford_u128`] for input that
+/// isn't available as `&str`. A byte that is not part of the alphabet results in
+/// `InvalidChar` containing `char::from(byte)` and its position.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// assert_eq!(parse_crockford_u128_bytes(b"0000000000000000000000007Z"), Ok(0xFF));
+/// assert_eq!(
+///     parse_crockford_u128_bytes(b"0000000000000000000000007"),
+///     Err(DecodingError::InvalidLength {
+///         expected: 26,
+///         actual: 25
+///     })
+/// );
+/// assert_eq!(
+///     parse_crockford_u128_bytes(b"0000000000000000000000000\xFC"),
+///     Err(DecodingError::InvalidChar {
+///         character: '\u{FC}',
+///         position: 25
+///     })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// The same as [`parse_crockford_u128`].
+pub fn parse_crockford_u128_bytes(input: &[u8]) -> Result<u128, DecodingError> {
+    if input.len() != 26 {
+        return Err(DecodingError::InvalidLength {
+            expected: 26,
+            actual: input.len(),
+        });
+    }
+
+    let highest = resolve_u128_value_for_byte(input, 0)?;
     if highest > 7 {
         return Err(DecodingError::DataTypeOverflow);
     }
 
     let mut result: u128 = highest << 125;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 120;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 115;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 110;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 105;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 100;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 95;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 90;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 85;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 80;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 75;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 70;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 65;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 60;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 55;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 50;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 45;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 40;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 35;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 30;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 25;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 20;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 15;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 10;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())? << 5;
-    result |= resolve_u128_value_for_char(chars.next().unwrap())?;
+    result |= resolve_u128_value_for_byte(input, 1)? << 120;
+    result |= resolve_u128_value_for_byte(input, 2)? << 115;
+    result |= resolve_u128_value_for_byte(input, 3)? << 110;
+    result |= resolve_u128_value_for_byte(input, 4)? << 105;
+    result |= resolve_u128_value_for_byte(input, 5)? << 100;
+    result |= resolve_u128_value_for_byte(input, 6)? << 95;
+    result |= resolve_u128_value_for_byte(input, 7)? << 90;
+    result |= resolve_u128_value_for_byte(input, 8)? << 85;
+    result |= resolve_u128_value_for_byte(input, 9)? << 80;
+    result |= resolve_u128_value_for_byte(input, 10)? << 75;
+    result |= resolve_u128_value_for_byte(input, 11)? << 70;
+    result |= resolve_u128_value_for_byte(input, 12)? << 65;
+    result |= resolve_u128_value_for_byte(input, 13)? << 60;
+    result |= resolve_u128_value_for_byte(input, 14)? << 55;
+    result |= resolve_u128_value_for_byte(input, 15)? << 50;
+    result |= resolve_u128_value_for_byte(input, 16)? << 45;
+    result |= resolve_u128_value_for_byte(input, 17)? << 40;
+    result |= resolve_u128_value_for_byte(input, 18)? << 35;
+    result |= resolve_u128_value_for_byte(input, 19)? << 30;
+    result |= resolve_u128_value_for_byte(input, 20)? << 25;
+    result |= resolve_u128_value_for_byte(input, 21)? << 20;
+    result |= resolve_u128_value_for_byte(input, 22)? << 15;
+    result |= resolve_u128_value_for_byte(input, 23)? << 10;
+    result |= resolve_u128_value_for_byte(input, 24)? << 5;
+    result |= resolve_u128_value_for_byte(input, 25)?;
 
     Ok(result)
 }
@@ -354,36 +565,69 @@ pub fn parse_crockford_u128(input: &str) -> Result<u128, DecodingError> {
 ///
 /// assert_eq!(a_string, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
 /// ```
+#[cfg(feature = "std")]
 pub fn append_crockford_u64_tuple(value: (u64, u64), to_append_to: &mut String) {
-    to_append_to.push(ENCODING_DIGITS[(value.0 >> 61) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 56) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 51) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 46) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 41) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 36) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 31) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 26) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 21) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 16) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 11) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 6) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.0 >> 1) & MASK_U64) as usize]);
+    let mut buffer = [0; 26];
+    encode_crockford_u64_tuple_into(value, &mut buffer);
+    push_ascii(&buffer, to_append_to);
+}
+
+/// Writes the [crockford Base32][crockford] representation of the `(u64, u64)` into
+/// `buffer` as ASCII bytes, without any allocation.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// let mut buffer = [0; 26];
+/// encode_crockford_u64_tuple_into((0, 0xFF), &mut buffer);
+///
+/// assert_eq!(&buffer, b"0000000000000000000000007Z");
+/// ```
+pub fn encode_crockford_u64_tuple_into(value: (u64, u64), buffer: &mut [u8; 26]) {
+    buffer[0] = ALPHABET[(value.0 >> 61) as usize];
+    buffer[1] = ALPHABET[((value.0 >> 56) & MASK_U64) as usize];
+    buffer[2] = ALPHABET[((value.0 >> 51) & MASK_U64) as usize];
+    buffer[3] = ALPHABET[((value.0 >> 46) & MASK_U64) as usize];
+    buffer[4] = ALPHABET[((value.0 >> 41) & MASK_U64) as usize];
+    buffer[5] = ALPHABET[((value.0 >> 36) & MASK_U64) as usize];
+    buffer[6] = ALPHABET[((value.0 >> 31) & MASK_U64) as usize];
+    buffer[7] = ALPHABET[((value.0 >> 26) & MASK_U64) as usize];
+    buffer[8] = ALPHABET[((value.0 >> 21) & MASK_U64) as usize];
+    buffer[9] = ALPHABET[((value.0 >> 16) & MASK_U64) as usize];
+    buffer[10] = ALPHABET[((value.0 >> 11) & MASK_U64) as usize];
+    buffer[11] = ALPHABET[((value.0 >> 6) & MASK_U64) as usize];
+    buffer[12] = ALPHABET[((value.0 >> 1) & MASK_U64) as usize];
 
     let split = ((value.0 << 4) & MASK_U64) | ((value.1 >> 60) & MASK_U64);
-    to_append_to.push(ENCODING_DIGITS[split as usize]);
-
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 55) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 50) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 45) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 40) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 35) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 30) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 25) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 20) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 15) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 10) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[((value.1 >> 5) & MASK_U64) as usize]);
-    to_append_to.push(ENCODING_DIGITS[(value.1 & MASK_U64) as usize]);
+    buffer[13] = ALPHABET[split as usize];
+
+    buffer[14] = ALPHABET[((value.1 >> 55) & MASK_U64) as usize];
+    buffer[15] = ALPHABET[((value.1 >> 50) & MASK_U64) as usize];
+    buffer[16] = ALPHABET[((value.1 >> 45) & MASK_U64) as usize];
+    buffer[17] = ALPHABET[((value.1 >> 40) & MASK_U64) as usize];
+    buffer[18] = ALPHABET[((value.1 >> 35) & MASK_U64) as usize];
+    buffer[19] = ALPHABET[((value.1 >> 30) & MASK_U64) as usize];
+    buffer[20] = ALPHABET[((value.1 >> 25) & MASK_U64) as usize];
+    buffer[21] = ALPHABET[((value.1 >> 20) & MASK_U64) as usize];
+    buffer[22] = ALPHABET[((value.1 >> 15) & MASK_U64) as usize];
+    buffer[23] = ALPHABET[((value.1 >> 10) & MASK_U64) as usize];
+    buffer[24] = ALPHABET[((value.1 >> 5) & MASK_U64) as usize];
+    buffer[25] = ALPHABET[(value.1 & MASK_U64) as usize];
+}
+
+#[cfg(feature = "std")]
+fn push_ascii(buffer: &[u8; 26], to_append_to: &mut String) {
+    to_append_to.push_str(ascii_str(buffer));
+}
+
+/// Returns the encoded `buffer` as `&str`.
+pub(crate) fn ascii_str(buffer: &[u8; 26]) -> &str {
+    // the crockford alphabet only contains ASCII characters
+    core::str::from_utf8(buffer).expect("crockford strings are ASCII")
 }
 
 /// Parses the given [crockford Base32][crockford] string into a `(u64, u64)`.
@@ -407,10 +651,10 @@ pub fn append_crockford_u64_tuple(value: (u64, u64), to_append_to: &mut String)
 ///
 /// let parsed = parse_crockford_u64_tuple("00000000000000000x1iIlLoO0")?;
 ///
-/// let mut string_representation = String::new();
-/// append_crockford_u64_tuple(parsed, &mut string_representation);
+/// let mut buffer = [0; 26];
+/// encode_crockford_u64_tuple_into(parsed, &mut buffer);
 ///
-/// assert_eq!(string_representation, "00000000000000000X11111000");
+/// assert_eq!(&buffer, b"00000000000000000X11111000");
 /// # Ok::<(), rusty_ulid::DecodingError>(())
 /// ```
 ///
@@ -422,7 +666,13 @@ pub fn append_crockford_u64_tuple(value: (u64, u64), to_append_to: &mut String)
 ///
 /// let nope = parse_crockford_u64_tuple("1234567890123456789012345");
 ///
-/// assert_eq!(Err(DecodingError::InvalidLength), nope);
+/// assert_eq!(
+///     Err(DecodingError::InvalidLength {
+///         expected: 26,
+///         actual: 25
+///     }),
+///     nope
+/// );
 /// ```
 ///
 /// ```
@@ -430,7 +680,13 @@ pub fn append_crockford_u64_tuple(value: (u64, u64), to_append_to: &mut String)
 ///
 /// let nope = parse_crockford_u64_tuple("123456789012345678901234567");
 ///
-/// assert_eq!(Err(DecodingError::InvalidLength), nope);
+/// assert_eq!(
+///     Err(DecodingError::InvalidLength {
+///         expected: 26,
+///         actual: 27
+///     }),
+///     nope
+/// );
 /// ```
 ///
 /// Parsing 26 bytes results in `DataTypeOverflow` if the `(u64, u64)` would overflow.
@@ -448,66 +704,420 @@ pub fn append_crockford_u64_tuple(value: (u64, u64), to_append_to: &mut String)
 /// ```
 ///
 /// Parsing a string containing an invalid character results in `InvalidChar` containing
-/// the character.
+/// the character and its byte position.
 ///
 /// ```
 /// use rusty_ulid::crockford::*;
 ///
 /// let nope = parse_crockford_u64_tuple("0000000000000000000000000U");
 ///
-/// assert_eq!(Err(DecodingError::InvalidChar('U')), nope);
+/// assert_eq!(
+///     Err(DecodingError::InvalidChar {
+///         character: 'U',
+///         position: 25
+///     }),
+///     nope
+/// );
 /// ```
 pub fn parse_crockford_u64_tuple(input: &str) -> Result<(u64, u64), DecodingError> {
-    let length = input.len();
-    if length != 26 {
-        return Err(DecodingError::InvalidLength);
+    if input.len() != 26 {
+        return Err(DecodingError::InvalidLength {
+            expected: 26,
+            actual: input.len(),
+        });
+    }
+    if !input.is_ascii() {
+        return Err(non_ascii_error(input));
     }
 
-    let mut chars = input.chars();
-    let highest = resolve_u64_value_for_char(chars.next().unwrap())?;
+    parse_crockford_u64_tuple_bytes(input.as_bytes())
+}
+
+/// Parses the given [crockford Base32][crockford] ASCII bytes into a `(u64, u64)`.
+///
+/// This is the byte-oriented variant of [`parse_crockford_u64_tuple`] for input
+/// that isn't available as `&str`. A byte that is not part of the alphabet results
+/// in `InvalidChar` containing `char::from(byte)` and its position.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// assert_eq!(parse_crockford_u64_tuple_bytes(b"0000000000000000000000007Z"), Ok((0, 0xFF)));
+/// assert_eq!(
+///     parse_crockford_u64_tuple_bytes(b"80000000000000000000000000"),
+///     Err(DecodingError::DataTypeOverflow)
+/// );
+/// ```
+///
+/// # Errors
+///
+/// The same as [`parse_crockford_u64_tuple`].
+pub fn parse_crockford_u64_tuple_bytes(input: &[u8]) -> Result<(u64, u64), DecodingError> {
+    if input.len() != 26 {
+        return Err(DecodingError::InvalidLength {
+            expected: 26,
+            actual: input.len(),
+        });
+    }
+
+    let highest = resolve_u64_value_for_byte(input, 0)?;
     if highest > 7 {
         return Err(DecodingError::DataTypeOverflow);
     }
 
     let mut high: u64 = highest << 61;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 56;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 51;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 46;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 41;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 36;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 31;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 26;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 21;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 16;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 11;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 6;
-    high |= resolve_u64_value_for_char(chars.next().unwrap())? << 1;
-
-    let split = resolve_u64_value_for_char(chars.next().unwrap())?;
+    high |= resolve_u64_value_for_byte(input, 1)? << 56;
+    high |= resolve_u64_value_for_byte(input, 2)? << 51;
+    high |= resolve_u64_value_for_byte(input, 3)? << 46;
+    high |= resolve_u64_value_for_byte(input, 4)? << 41;
+    high |= resolve_u64_value_for_byte(input, 5)? << 36;
+    high |= resolve_u64_value_for_byte(input, 6)? << 31;
+    high |= resolve_u64_value_for_byte(input, 7)? << 26;
+    high |= resolve_u64_value_for_byte(input, 8)? << 21;
+    high |= resolve_u64_value_for_byte(input, 9)? << 16;
+    high |= resolve_u64_value_for_byte(input, 10)? << 11;
+    high |= resolve_u64_value_for_byte(input, 11)? << 6;
+    high |= resolve_u64_value_for_byte(input, 12)? << 1;
+
+    let split = resolve_u64_value_for_byte(input, 13)?;
     high |= split >> 4;
 
     let mut low: u64 = split << 60;
 
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 55;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 50;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 45;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 40;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 35;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 30;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 25;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 20;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 15;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 10;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())? << 5;
-    low |= resolve_u64_value_for_char(chars.next().unwrap())?;
+    low |= resolve_u64_value_for_byte(input, 14)? << 55;
+    low |= resolve_u64_value_for_byte(input, 15)? << 50;
+    low |= resolve_u64_value_for_byte(input, 16)? << 45;
+    low |= resolve_u64_value_for_byte(input, 17)? << 40;
+    low |= resolve_u64_value_for_byte(input, 18)? << 35;
+    low |= resolve_u64_value_for_byte(input, 19)? << 30;
+    low |= resolve_u64_value_for_byte(input, 20)? << 25;
+    low |= resolve_u64_value_for_byte(input, 21)? << 20;
+    low |= resolve_u64_value_for_byte(input, 22)? << 15;
+    low |= resolve_u64_value_for_byte(input, 23)? << 10;
+    low |= resolve_u64_value_for_byte(input, 24)? << 5;
+    low |= resolve_u64_value_for_byte(input, 25)?;
 
     Ok((high, low))
 }
 
+/// The 37 check symbols of [crockford Base32][crockford], in order of their value.
+///
+/// The first 32 check symbols are the same as the [`ALPHABET`].
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+pub const CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+fn check_symbol_u128(value: u128) -> char {
+    char::from(CHECK_SYMBOLS[(value % 37) as usize])
+}
+
+fn decode_check_symbol(symbol: char) -> Option<u128> {
+    match symbol {
+        '*' => Some(32),
+        '~' => Some(33),
+        '$' => Some(34),
+        '=' => Some(35),
+        'U' | 'u' => Some(36),
+        _ => u8::try_from(symbol)
+            .ok()
+            .and_then(decode_digit)
+            .map(u128::from),
+    }
+}
+
+fn verify_check_symbol(value: u128, symbol: char) -> Result<(), DecodingError> {
+    match decode_check_symbol(symbol) {
+        Some(check) if check == value % 37 => Ok(()),
+        Some(_) => Err(DecodingError::ChecksumMismatch {
+            expected: check_symbol_u128(value),
+            actual: symbol,
+        }),
+        None => Err(DecodingError::InvalidChar {
+            character: symbol,
+            position: 26,
+        }),
+    }
+}
+
+/// Splits a 27 character string into the 26 characters of the value and the check symbol.
+fn split_check_symbol(input: &str) -> Result<(&str, char), DecodingError> {
+    let mut chars = input.chars();
+    let symbol = chars.next_back();
+    let expected = 26 + symbol.map_or(1, char::len_utf8);
+    match symbol {
+        Some(symbol) if input.len() == expected => Ok((chars.as_str(), symbol)),
+        _ => Err(DecodingError::InvalidLength {
+            expected,
+            actual: input.len(),
+        }),
+    }
+}
+
+/// Appends the [crockford Base32][crockford] representation of the `u128` followed by
+/// its mod-37 check symbol to `to_append_to`.
+///
+/// The check symbol allows detecting transcription errors of identifiers typed by humans.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_ulid::crockford::*;
+/// let mut a_string = String::new();
+/// append_crockford_u128_with_check(36, &mut a_string);
+/// assert_eq!(a_string, "00000000000000000000000014U");
+/// ```
+#[cfg(feature = "std")]
+pub fn append_crockford_u128_with_check(value: u128, to_append_to: &mut String) {
+    append_crockford_u128(value, to_append_to);
+    to_append_to.push(check_symbol_u128(value));
+}
+
+/// Parses the given 27 character [crockford Base32][crockford] string, consisting of
+/// a `u128` followed by its mod-37 check symbol.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_ulid::crockford::*;
+/// assert_eq!(parse_crockford_u128_with_check("00000000000000000000000014U"), Ok(36));
+/// assert_eq!(parse_crockford_u128_with_check("00000000000000000000000014u"), Ok(36));
+/// ```
+///
+/// # Errors
+///
+/// Parsing a string with other than 27 characters results in `InvalidLength`.
+/// A check symbol that doesn't match the value results in `ChecksumMismatch`, a
+/// character that isn't a check symbol at all in `InvalidChar` at position 26.
+/// Otherwise, the errors are the same as for [`parse_crockford_u128`].
+///
+/// ```
+/// # use rusty_ulid::crockford::*;
+/// assert_eq!(
+///     parse_crockford_u128_with_check("00000000000000000000000015U"),
+///     Err(DecodingError::ChecksumMismatch {
+///         expected: '0',
+///         actual: 'U'
+///     })
+/// );
+/// assert_eq!(
+///     parse_crockford_u128_with_check("00000000000000000000000015!"),
+///     Err(DecodingError::InvalidChar {
+///         character: '!',
+///         position: 26
+///     })
+/// );
+/// assert_eq!(
+///     parse_crockford_u128_with_check("00000000000000000000000014"),
+///     Err(DecodingError::InvalidLength {
+///         expected: 27,
+///         actual: 26
+///     })
+/// );
+/// ```
+pub fn parse_crockford_u128_with_check(input: &str) -> Result<u128, DecodingError> {
+    let (input, symbol) = split_check_symbol(input)?;
+    let value = parse_crockford_u128(input)?;
+    verify_check_symbol(value, symbol)?;
+
+    Ok(value)
+}
+
+/// Appends the [crockford Base32][crockford] representation of the `(u64, u64)`
+/// followed by its mod-37 check symbol to `to_append_to`.
+///
+/// The check symbol of `(high, low)` is the same as the one of the `u128`
+/// `(high << 64) | low`.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_ulid::crockford::*;
+/// let mut a_string = String::new();
+/// append_crockford_u64_tuple_with_check((0, 32), &mut a_string);
+/// assert_eq!(a_string, "00000000000000000000000010*");
+/// ```
+#[cfg(feature = "std")]
+pub fn append_crockford_u64_tuple_with_check(value: (u64, u64), to_append_to: &mut String) {
+    append_crockford_u64_tuple(value, to_append_to);
+    to_append_to.push(check_symbol_u128(u64_tuple_to_u128(value)));
+}
+
+/// Parses the given 27 character [crockford Base32][crockford] string, consisting of
+/// a `(u64, u64)` followed by its mod-37 check symbol.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_ulid::crockford::*;
+/// assert_eq!(
+///     parse_crockford_u64_tuple_with_check("00000000000000000000000010*"),
+///     Ok((0, 32))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// The same as [`parse_crockford_u128_with_check`].
+pub fn parse_crockford_u64_tuple_with_check(input: &str) -> Result<(u64, u64), DecodingError> {
+    let (input, symbol) = split_check_symbol(input)?;
+    let value = parse_crockford_u64_tuple(input)?;
+    verify_check_symbol(u64_tuple_to_u128(value), symbol)?;
+
+    Ok(value)
+}
+
+fn u64_tuple_to_u128(value: (u64, u64)) -> u128 {
+    (u128::from(value.0) << 64) | u128::from(value.1)
+}
+
+/// Returns the [crockford Base32][crockford] representation of arbitrary `bytes`.
+///
+/// The bytes are encoded as a big-endian bit stream, five bits per symbol. The
+/// last symbol is padded with zero bits if the number of bits is not a multiple
+/// of five. No check symbol or padding characters are added.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// assert_eq!(encode_bytes(b""), "");
+/// assert_eq!(encode_bytes(&[0xFF]), "ZW");
+/// assert_eq!(encode_bytes(b"hello"), "D1JPRV3F");
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity((bytes.len() * 8).div_ceil(5));
+
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | u16::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(char::from(ALPHABET[usize::from((buffer >> bits) & 0x1F)]));
+        }
+    }
+    if bits > 0 {
+        result.push(char::from(
+            ALPHABET[usize::from((buffer << (5 - bits)) & 0x1F)],
+        ));
+    }
+
+    result
+}
+
+/// Decodes a [crockford Base32][crockford] string of any length created by
+/// [`encode_bytes`] back into bytes.
+///
+/// When decoding, upper and lower case letters are accepted,
+/// `i` and `l` will be treated as `1` and `o` will be treated as `0`.
+///
+/// [crockford]: https://crockford.com/wrmg/base32.html
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// assert_eq!(decode_to_vec("D1JPRV3F")?, b"hello");
+/// assert_eq!(decode_to_vec("d1jprv3f")?, b"hello");
+/// assert_eq!(decode_to_vec("")?, b"");
+/// # Ok::<(), DecodingError>(())
+/// ```
+///
+/// # Errors
+///
+/// Decoding a string containing an invalid character results in `InvalidChar`
+/// containing the character and its byte position.
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// assert_eq!(
+///     decode_to_vec("D1JPRV3U"),
+///     Err(DecodingError::InvalidChar {
+///         character: 'U',
+///         position: 7
+///     })
+/// );
+/// ```
+///
+/// A number of symbols that [`encode_bytes`] can't create results in
+/// `InvalidLength` containing the next shorter valid length, non-zero padding
+/// bits in the last symbol result in `DataTypeOverflow`.
+///
+/// ```
+/// use rusty_ulid::crockford::*;
+///
+/// assert_eq!(
+///     decode_to_vec("Z"),
+///     Err(DecodingError::InvalidLength {
+///         expected: 0,
+///         actual: 1
+///     })
+/// );
+/// assert_eq!(decode_to_vec("ZZ"), Err(DecodingError::DataTypeOverflow));
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_to_vec(input: &str) -> Result<Vec<u8>, DecodingError> {
+    let mut result = Vec::with_capacity(input.len() * 5 / 8);
+
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for (position, character) in input.char_indices() {
+        let value = match u8::try_from(character).ok().and_then(decode_digit) {
+            Some(value) => value,
+            None => {
+                return Err(DecodingError::InvalidChar {
+                    character,
+                    position,
+                })
+            }
+        };
+        buffer = (buffer << 5) | u16::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+
+    // encode_bytes pads with less than five zero bits
+    if bits >= 5 {
+        return Err(DecodingError::InvalidLength {
+            expected: input.len() - 1,
+            actual: input.len(),
+        });
+    }
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(DecodingError::DataTypeOverflow);
+    }
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn append_crockford_u128_test_cases() {
         single_append_crockford_u128(0, "00000000000000000000000000");
@@ -649,15 +1259,22 @@ mod tests {
 
         single_parse_crockford_u128(
             "0000000000000000000000000U",
-            Err(DecodingError::InvalidChar('U')),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25,
+            }),
         );
 
         single_parse_crockford_u128(
             "123456789012345678901234567",
-            Err(DecodingError::InvalidLength),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 27,
+            }),
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn append_crockford_u64_tuple_test_cases() {
         single_append_crockford_u64_tuple(
@@ -758,16 +1375,25 @@ mod tests {
 
         single_parse_crockford_u64_tuple(
             "0000000000000000000000000U",
-            Err(DecodingError::InvalidChar('U')),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25,
+            }),
         );
 
         single_parse_crockford_u64_tuple(
             "1234567890123456789012345",
-            Err(DecodingError::InvalidLength),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25,
+            }),
         );
         single_parse_crockford_u64_tuple(
             "123456789012345678901234567",
-            Err(DecodingError::InvalidLength),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 27,
+            }),
         );
         single_parse_crockford_u64_tuple("00000000000000000000000000", Ok((0, 0)));
         single_parse_crockford_u64_tuple(
@@ -784,23 +1410,213 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_non_ascii_reports_first_invalid_char() {
+        let input = "0000000000000000000000Ü0U";
+        assert_eq!(input.len(), 26);
+
+        assert_eq!(
+            parse_crockford_u64_tuple(input),
+            Err(DecodingError::InvalidChar {
+                character: 'Ü',
+                position: 22,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u128(input),
+            Err(DecodingError::InvalidChar {
+                character: 'Ü',
+                position: 22,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u64_tuple("U000000000000000000000Ü00"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 0,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u128("8000000000000000000000Ü00"),
+            Err(DecodingError::DataTypeOverflow)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_symbol_round_trip() {
+        let values = [
+            0,
+            1,
+            36,
+            37,
+            0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67,
+            u128::MAX,
+        ];
+        for value in values {
+            let mut a_string = String::new();
+            append_crockford_u128_with_check(value, &mut a_string);
+            assert_eq!(a_string.len(), 27);
+            assert_eq!(parse_crockford_u128_with_check(&a_string), Ok(value));
+
+            let tuple = ((value >> 64) as u64, value as u64);
+            let mut tuple_string = String::new();
+            append_crockford_u64_tuple_with_check(tuple, &mut tuple_string);
+            assert_eq!(tuple_string, a_string);
+            assert_eq!(
+                parse_crockford_u64_tuple_with_check(&tuple_string),
+                Ok(tuple)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_symbol_detects_transposition() {
+        // swapped 'R' and 'D' of 01CAH7NXGRDJNE9B1NY7PQGYV7
+        let mut a_string = String::new();
+        append_crockford_u128_with_check(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67, &mut a_string);
+        let check = a_string.pop().unwrap();
+        assert_eq!(a_string, "01CAH7NXGRDJNE9B1NY7PQGYV7");
+
+        let transposed = format!("01CAH7NXGDRJNE9B1NY7PQGYV7{check}");
+        assert_eq!(
+            parse_crockford_u128_with_check(&transposed),
+            Err(DecodingError::ChecksumMismatch {
+                expected: check_symbol_u128(
+                    parse_crockford_u128("01CAH7NXGDRJNE9B1NY7PQGYV7").unwrap()
+                ),
+                actual: check,
+            })
+        );
+    }
+
+    #[test]
+    fn check_symbol_errors() {
+        assert_eq!(
+            parse_crockford_u128_with_check("0000000000000000000000000000"),
+            Err(DecodingError::InvalidLength {
+                expected: 27,
+                actual: 28,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u128_with_check("0000000000000000000000000Ü"),
+            Err(DecodingError::InvalidLength {
+                expected: 28,
+                actual: 27,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u128_with_check("00000000000000000000000000*"),
+            Err(DecodingError::ChecksumMismatch {
+                expected: '0',
+                actual: '*',
+            })
+        );
+        assert_eq!(
+            parse_crockford_u64_tuple_with_check("0000000000000000000000000Z~"),
+            Err(DecodingError::ChecksumMismatch {
+                expected: 'Z',
+                actual: '~',
+            })
+        );
+        assert_eq!(
+            parse_crockford_u128_with_check("00000000000000000000000000!"),
+            Err(DecodingError::InvalidChar {
+                character: '!',
+                position: 26,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u64_tuple_with_check("0000000000000000000000000U0"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25,
+            })
+        );
+        assert_eq!(
+            parse_crockford_u64_tuple_with_check("80000000000000000000000000U"),
+            Err(DecodingError::DataTypeOverflow)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bytes_round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        for length in 0..bytes.len() {
+            let encoded = encode_bytes(&bytes[..length]);
+            assert_eq!(encoded.len(), (length * 8).div_ceil(5));
+            assert_eq!(decode_to_vec(&encoded), Ok(bytes[..length].to_vec()));
+        }
+    }
+
+    #[test]
+    fn decode_digit_test_cases() {
+        for (value, symbol) in ALPHABET.iter().enumerate() {
+            assert_eq!(decode_digit(*symbol), Some(value as u8));
+            assert_eq!(decode_digit(symbol.to_ascii_lowercase()), Some(value as u8));
+        }
+
+        assert_eq!(decode_digit(b'I'), Some(1));
+        assert_eq!(decode_digit(b'i'), Some(1));
+        assert_eq!(decode_digit(b'L'), Some(1));
+        assert_eq!(decode_digit(b'l'), Some(1));
+        assert_eq!(decode_digit(b'O'), Some(0));
+        assert_eq!(decode_digit(b'o'), Some(0));
+
+        assert_eq!(decode_digit(b'U'), None);
+        assert_eq!(decode_digit(b'u'), None);
+        assert_eq!(decode_digit(b'-'), None);
+        assert_eq!(decode_digit(0xFF), None);
+    }
+
     #[test]
     fn decoding_error_display_trait() {
-        single_decoding_error_display_trait(DecodingError::InvalidLength, "invalid length");
         single_decoding_error_display_trait(
-            DecodingError::InvalidChar('U'),
-            "invalid character 'U'",
+            DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25,
+            },
+            "invalid length 25, expected 26",
+        );
+        single_decoding_error_display_trait(
+            DecodingError::InvalidChar {
+                character: 'U',
+                position: 25,
+            },
+            "invalid character 'U' at position 25",
         );
         single_decoding_error_display_trait(DecodingError::DataTypeOverflow, "data type overflow");
+        single_decoding_error_display_trait(
+            DecodingError::ChecksumMismatch {
+                expected: 'Z',
+                actual: '~',
+            },
+            "check symbol '~' doesn't match, expected 'Z'",
+        );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn decoding_error_sources() {
-        assert!(DecodingError::InvalidLength.source().is_none());
-        assert!(DecodingError::InvalidChar('a').source().is_none());
+        assert!(DecodingError::InvalidLength {
+            expected: 26,
+            actual: 0,
+        }
+        .source()
+        .is_none());
+        assert!(DecodingError::InvalidChar {
+            character: 'a',
+            position: 0,
+        }
+        .source()
+        .is_none());
         assert!(DecodingError::DataTypeOverflow.source().is_none());
     }
 
+    #[cfg(feature = "std")]
     fn single_append_crockford_u128(value: u128, expected_result: &str) {
         let mut a_string = String::new();
         append_crockford_u128(value, &mut a_string);
@@ -812,8 +1628,15 @@ mod tests {
         let result = parse_crockford_u128(value);
         println!("parse_crockford_u128({value}) => {result:?}");
         assert_eq!(result, expected_result);
+        if value.is_ascii() {
+            assert_eq!(
+                parse_crockford_u128_bytes(value.as_bytes()),
+                expected_result
+            );
+        }
     }
 
+    #[cfg(feature = "std")]
     fn single_append_crockford_u64_tuple(value: (u64, u64), expected_result: &str) {
         let mut a_string = String::new();
         append_crockford_u64_tuple(value, &mut a_string);
@@ -827,6 +1650,12 @@ mod tests {
     ) {
         let result = parse_crockford_u64_tuple(value);
         assert_eq!(result, expected_result);
+        if value.is_ascii() {
+            assert_eq!(
+                parse_crockford_u64_tuple_bytes(value.as_bytes()),
+                expected_result
+            );
+        }
     }
 
     fn single_decoding_error_display_trait(error: DecodingError, expected_result: &str) {
@@ -0,0 +1,148 @@
+//! [Diesel](https://diesel.rs) SQL type support.
+//!
+//! # Enabling
+//!
+//! This module is only available when at least one of the `diesel-postgres`,
+//! `diesel-mysql` and `diesel-sqlite` features is enabled. Enable the ones of
+//! the backends you use in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["diesel-sqlite"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`AsExpression`](diesel::expression::AsExpression) and
+//! [`FromSqlRow`](diesel::deserialize::FromSqlRow) for the following SQL types:
+//!
+//! | SQL type         | Backends                | Features                                           |
+//! |------------------|-------------------------|----------------------------------------------------|
+//! | [`Binary`]       | Postgres, MySQL, SQLite | `diesel-postgres`, `diesel-mysql`, `diesel-sqlite` |
+//! | `Uuid`           | Postgres                | `diesel-postgres`                                  |
+//!
+//! [`Binary`]: diesel::sql_types::Binary
+//!
+//! Values are stored as 16 bytes using the same big-endian byte order as
+//! `<[u8; 16]>::from(ulid)`, so they sort just like the ULIDs themselves.
+//!
+//! ```rust
+//! use diesel::prelude::*;
+//! use rusty_ulid::Ulid;
+//!
+//! diesel::table! {
+//!     events (id) {
+//!         id -> Binary,
+//!         name -> Text,
+//!     }
+//! }
+//!
+//! #[derive(Queryable, Insertable)]
+//! #[diesel(table_name = events)]
+//! struct Event {
+//!     id: Ulid,
+//!     name: String,
+//! }
+//! ```
+
+use crate::Ulid;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+#[cfg(feature = "diesel-mysql")]
+use diesel::mysql::Mysql;
+#[cfg(feature = "diesel-postgres")]
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Binary;
+#[cfg(feature = "diesel-postgres")]
+use diesel::sql_types::Uuid;
+#[cfg(feature = "diesel-sqlite")]
+use diesel::sqlite::Sqlite;
+#[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+use std::io::Write;
+
+#[cfg(feature = "diesel-postgres")]
+impl ToSql<Binary, Pg> for Ulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&<[u8; 16]>::from(*self))?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-mysql")]
+impl ToSql<Binary, Mysql> for Ulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        out.write_all(&<[u8; 16]>::from(*self))?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Binary, Sqlite> for Ulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(<[u8; 16]>::from(*self).to_vec());
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB> FromSql<Binary, DB> for Ulid
+where
+    DB: Backend,
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::try_from(
+            <Vec<u8> as FromSql<Binary, DB>>::from_sql(bytes)?.as_slice(),
+        )?)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl ToSql<Uuid, Pg> for Ulid {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <Self as ToSql<Binary, Pg>>::to_sql(self, out)
+    }
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl FromSql<Uuid, Pg> for Ulid {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::try_from(value.as_bytes())?)
+    }
+}
+
+#[cfg(all(test, feature = "diesel-sqlite"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqlite_round_trip() {
+        use diesel::connection::Connection;
+        use diesel::sqlite::SqliteConnection;
+        use diesel::RunQueryDsl;
+
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+        let result: Ulid = diesel::select(diesel::dsl::sql::<Binary>("").bind::<Binary, _>(ulid))
+            .get_result(&mut connection)
+            .unwrap();
+
+        assert_eq!(result, ulid);
+    }
+
+    #[test]
+    fn sqlite_rejects_wrong_length() {
+        use diesel::connection::Connection;
+        use diesel::sqlite::SqliteConnection;
+        use diesel::RunQueryDsl;
+
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+        let result: Result<Ulid, _> =
+            diesel::select(diesel::dsl::sql::<Binary>("X'0102'")).get_result(&mut connection);
+
+        assert!(result.is_err());
+    }
+}
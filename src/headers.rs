@@ -0,0 +1,88 @@
+//! Typed `X-Request-Id` header for the [headers](https://docs.rs/headers) crate.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `headers` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["headers"]
+//! ```
+//!
+//! # Usage
+//!
+//! [`XRequestId`] is read from and written to a `HeaderMap` with
+//! [`HeaderMapExt`](headers::HeaderMapExt):
+//!
+//! ```rust
+//! use headers::HeaderMapExt;
+//! use http::HeaderMap;
+//! use rusty_ulid::headers::XRequestId;
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.typed_insert(XRequestId(ulid));
+//! assert_eq!(headers["x-request-id"], "01CAH7NXGRDJNE9B1NY7PQGYV7");
+//!
+//! assert_eq!(headers.typed_get(), Some(XRequestId(ulid)));
+//! ```
+
+use crate::Ulid;
+use headers::{Error, Header};
+use http::{HeaderName, HeaderValue};
+
+static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The `X-Request-Id` header containing a ULID.
+///
+/// Decoding fails if the header occurs more than once or doesn't contain a
+/// valid ULID string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct XRequestId(pub Ulid);
+
+impl Header for XRequestId {
+    fn name() -> &'static HeaderName {
+        &X_REQUEST_ID
+    }
+
+    fn decode<'i, I>(values: &mut I) -> Result<Self, Error>
+    where
+        I: Iterator<Item = &'i HeaderValue>,
+    {
+        let value = values.next().ok_or_else(Error::invalid)?;
+        if values.next().is_some() {
+            return Err(Error::invalid());
+        }
+        Ulid::try_from(value)
+            .map(Self)
+            .map_err(|_| Error::invalid())
+    }
+
+    fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+        values.extend(Some(HeaderValue::from(self.0)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use headers::HeaderMapExt;
+    use http::HeaderMap;
+
+    #[test]
+    fn invalid_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(headers.typed_get::<XRequestId>(), None);
+
+        let _ = headers.insert(&X_REQUEST_ID, HeaderValue::from_static("nope"));
+        assert!(headers.typed_try_get::<XRequestId>().is_err());
+
+        let _ = headers.insert(&X_REQUEST_ID, HeaderValue::from(Ulid::from(0)));
+        let _ = headers.append(&X_REQUEST_ID, HeaderValue::from(Ulid::from(1)));
+        assert!(headers.typed_try_get::<XRequestId>().is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! [http](https://docs.rs/http) header value conversions.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `http` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["http"]
+//! ```
+//!
+//! # Usage
+//!
+//! A `Ulid` converts into a [`HeaderValue`] containing the canonical ULID string
+//! and can be parsed from one, e.g. for request IDs:
+//!
+//! ```rust
+//! use http::{HeaderMap, HeaderValue};
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.insert("x-request-id", HeaderValue::from(ulid));
+//! assert_eq!(headers["x-request-id"], "01CAH7NXGRDJNE9B1NY7PQGYV7");
+//!
+//! assert_eq!(Ulid::try_from(&headers["x-request-id"]), Ok(ulid));
+//! ```
+
+use crate::{DecodingError, Ulid};
+use http::HeaderValue;
+
+impl From<Ulid> for HeaderValue {
+    fn from(ulid: Ulid) -> Self {
+        // the crockford alphabet only contains visible ASCII characters
+        HeaderValue::from_bytes(&ulid.to_ascii_bytes())
+            .expect("ULID strings are valid header values")
+    }
+}
+
+impl TryFrom<&HeaderValue> for Ulid {
+    type Error = DecodingError;
+
+    fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+        Self::from_ascii_slice(value.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let value = HeaderValue::from(ulid);
+
+        assert_eq!(value, "01CAH7NXGRDJNE9B1NY7PQGYV7");
+        assert_eq!(Ulid::try_from(&value), Ok(ulid));
+        assert_eq!(
+            Ulid::try_from(&HeaderValue::from_static("01cah7nxgrdjne9b1ny7pqgyv7")),
+            Ok(ulid)
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(
+            Ulid::try_from(&HeaderValue::from_static("01CAH7NXGRDJNE9B1NY7PQGYV")),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25
+            })
+        );
+        assert_eq!(
+            Ulid::try_from(&HeaderValue::from_bytes(b"01CAH7NXGRDJNE9B1NY7PQGYV\xFC").unwrap()),
+            Err(DecodingError::InvalidChar {
+                character: '\u{FC}',
+                position: 25
+            })
+        );
+    }
+}
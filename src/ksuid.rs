@@ -0,0 +1,260 @@
+//! Conversions between ULIDs and [KSUIDs][ksuid] for migrations between both schemes.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `ksuid` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["ksuid"]
+//! ```
+//!
+//! # Lossiness
+//!
+//! A KSUID consists of a 32-bit timestamp in seconds since [`KSUID_EPOCH`]
+//! followed by a 128-bit payload, a ULID of a 48-bit timestamp in milliseconds
+//! since the UNIX epoch followed by 80 random bits. The conversions map the
+//! timestamps onto each other and keep the sort order, but are lossy:
+//!
+//! - ULID to KSUID drops the milliseconds of the timestamp. The 80 random bits
+//!   become the first 10 bytes of the payload, the remaining 6 bytes are zero.
+//!   ULIDs created before `2014-05-13T16:53:20Z` or after `2150-06-19T23:21:35Z`
+//!   can't be converted.
+//! - KSUID to ULID uses the full second as timestamp and the first 10 bytes of
+//!   the payload as random part. The last 6 bytes of the payload are dropped.
+//!
+//! ```rust
+//! use rusty_ulid::{ksuid, Ulid};
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let ksuid = ksuid::to_ksuid_string(ulid).unwrap();
+//! assert_eq!(ksuid, "12voMlGnVPj7dubeBMQ2QDc7DFY");
+//!
+//! let converted = ksuid::from_ksuid_str(&ksuid)?;
+//! assert_eq!(converted.timestamp(), 1_523_144_390_000);
+//! assert_eq!(converted.to_string()[10..], ulid.to_string()[10..]);
+//! # Ok::<(), rusty_ulid::DecodingError>(())
+//! ```
+//!
+//! [ksuid]: https://github.com/segmentio/ksuid
+
+use crate::{DecodingError, Ulid};
+
+/// The epoch of KSUID timestamps in seconds since the UNIX epoch, i.e. `2014-05-13T16:53:20Z`.
+pub const KSUID_EPOCH: u64 = 1_400_000_000;
+
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Returns the 20 bytes of the KSUID corresponding to `ulid`.
+///
+/// Returns `None` if the timestamp of `ulid` can't be represented by a KSUID.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::{ksuid, Ulid};
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+/// let ksuid = ksuid::to_ksuid(ulid).unwrap();
+///
+/// assert_eq!(u32::from_be_bytes([ksuid[0], ksuid[1], ksuid[2], ksuid[3]]), 123_144_390);
+/// assert_eq!(ksuid[4..14], ulid.as_bytes()[6..]);
+/// assert_eq!(ksuid[14..], [0; 6]);
+///
+/// assert_eq!(ksuid::to_ksuid(Ulid::from(0)), None);
+/// ```
+#[must_use]
+pub fn to_ksuid(ulid: Ulid) -> Option<[u8; 20]> {
+    let seconds = (ulid.timestamp() / 1000).checked_sub(KSUID_EPOCH)?;
+    let seconds = u32::try_from(seconds).ok()?;
+
+    let mut ksuid = [0; 20];
+    ksuid[..4].copy_from_slice(&seconds.to_be_bytes());
+    ksuid[4..14].copy_from_slice(&ulid.as_bytes()[6..]);
+    Some(ksuid)
+}
+
+/// Returns the ULID corresponding to the 20 bytes of `ksuid`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::{ksuid, Ulid};
+///
+/// let mut ksuid = [0xFF; 20];
+/// ksuid[..4].copy_from_slice(&123_144_390_u32.to_be_bytes());
+///
+/// let ulid = ksuid::from_ksuid(ksuid);
+///
+/// assert_eq!(ulid.timestamp(), 1_523_144_390_000);
+/// assert_eq!(ulid.as_bytes()[6..], [0xFF; 10]);
+/// ```
+#[must_use]
+pub fn from_ksuid(ksuid: [u8; 20]) -> Ulid {
+    let seconds = u32::from_be_bytes([ksuid[0], ksuid[1], ksuid[2], ksuid[3]]);
+    let timestamp = (u64::from(seconds) + KSUID_EPOCH) * 1000;
+
+    let mut bytes = [0; 16];
+    bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+    bytes[6..].copy_from_slice(&ksuid[4..14]);
+    Ulid::from(bytes)
+}
+
+/// Returns the 27 character base62 string of the KSUID corresponding to `ulid`.
+///
+/// Returns `None` if the timestamp of `ulid` can't be represented by a KSUID.
+#[must_use]
+pub fn to_ksuid_string(ulid: Ulid) -> Option<String> {
+    to_ksuid(ulid).map(|ksuid| encode_base62(&ksuid))
+}
+
+/// Parses the 27 character base62 string of a KSUID and returns the corresponding ULID.
+///
+/// # Errors
+///
+/// Parsing a string with other than 27 bytes results in `InvalidLength`.
+///
+/// Parsing a string containing a character outside of the base62 alphabet results
+/// in `InvalidChar` containing the character and its byte position.
+///
+/// Parsing a string that exceeds the largest KSUID `aWgEPTl1tmebfsQzFP4bxwgy80V`
+/// results in `DataTypeOverflow`.
+pub fn from_ksuid_str(input: &str) -> Result<Ulid, DecodingError> {
+    decode_base62(input).map(from_ksuid)
+}
+
+fn encode_base62(ksuid: &[u8; 20]) -> String {
+    let mut number = *ksuid;
+    let mut digits = [b'0'; 27];
+    for digit in digits.iter_mut().rev() {
+        // long division of the big-endian number by 62
+        let mut remainder = 0;
+        for byte in &mut number {
+            let value = (remainder << 8) | u32::from(*byte);
+            *byte = (value / 62) as u8;
+            remainder = value % 62;
+        }
+        *digit = BASE62_ALPHABET[remainder as usize];
+    }
+
+    digits.iter().map(|digit| char::from(*digit)).collect()
+}
+
+fn decode_base62(input: &str) -> Result<[u8; 20], DecodingError> {
+    if input.len() != 27 {
+        return Err(DecodingError::InvalidLength {
+            expected: 27,
+            actual: input.len(),
+        });
+    }
+
+    let mut number = [0_u8; 20];
+    for (position, character) in input.char_indices() {
+        let digit = match character {
+            '0'..='9' => u32::from(character) - u32::from('0'),
+            'A'..='Z' => u32::from(character) - u32::from('A') + 10,
+            'a'..='z' => u32::from(character) - u32::from('a') + 36,
+            _ => {
+                return Err(DecodingError::InvalidChar {
+                    character,
+                    position,
+                })
+            }
+        };
+
+        // multiply the big-endian number by 62 and add the digit
+        let mut carry = digit;
+        for byte in number.iter_mut().rev() {
+            let value = u32::from(*byte) * 62 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return Err(DecodingError::DataTypeOverflow);
+        }
+    }
+
+    Ok(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // example of https://github.com/segmentio/ksuid#command-line-tool
+    const KSUID_STRING: &str = "0ujtsYcgvSTl8PAuAdqWYSMnLOv";
+    const KSUID_BYTES: [u8; 20] = [
+        0x06, 0x69, 0xF7, 0xEF, 0xB5, 0xA1, 0xCD, 0x34, 0xB5, 0xF9, 0x9D, 0x11, 0x54, 0xFB, 0x68,
+        0x53, 0x34, 0x5C, 0x97, 0x35,
+    ];
+
+    #[test]
+    fn base62_matches_reference() {
+        assert_eq!(encode_base62(&KSUID_BYTES), KSUID_STRING);
+        assert_eq!(decode_base62(KSUID_STRING), Ok(KSUID_BYTES));
+    }
+
+    #[test]
+    fn base62_boundaries() {
+        assert_eq!(encode_base62(&[0; 20]), "000000000000000000000000000");
+        assert_eq!(encode_base62(&[0xFF; 20]), "aWgEPTl1tmebfsQzFP4bxwgy80V");
+        assert_eq!(decode_base62("aWgEPTl1tmebfsQzFP4bxwgy80V"), Ok([0xFF; 20]));
+        assert_eq!(
+            decode_base62("aWgEPTl1tmebfsQzFP4bxwgy80W"),
+            Err(DecodingError::DataTypeOverflow)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            from_ksuid_str("0ujtsYcgvSTl8PAuAdqWYSMnLO"),
+            Err(DecodingError::InvalidLength {
+                expected: 27,
+                actual: 26
+            })
+        );
+        assert_eq!(
+            from_ksuid_str("0ujtsYcgvSTl8PAuAdqWYSMnLO-"),
+            Err(DecodingError::InvalidChar {
+                character: '-',
+                position: 26
+            })
+        );
+    }
+
+    #[test]
+    fn conversion_keeps_timestamp_seconds_and_random_bits() {
+        let ulid = from_ksuid(KSUID_BYTES);
+
+        assert_eq!(ulid.timestamp(), (107_608_047 + KSUID_EPOCH) * 1000);
+        assert_eq!(ulid.as_bytes()[6..], KSUID_BYTES[4..14]);
+
+        let ksuid = to_ksuid(ulid).unwrap();
+        assert_eq!(ksuid[..14], KSUID_BYTES[..14]);
+        assert_eq!(ksuid[14..], [0; 6]);
+    }
+
+    #[test]
+    fn timestamps_outside_of_ksuid_range_are_rejected() {
+        let first = Ulid::min_for_timestamp(KSUID_EPOCH * 1000);
+        let last = Ulid::max_for_timestamp((KSUID_EPOCH + u64::from(u32::MAX)) * 1000 + 999);
+
+        assert!(to_ksuid(first).is_some());
+        assert!(to_ksuid(last).is_some());
+        assert_eq!(
+            to_ksuid(Ulid::max_for_timestamp(KSUID_EPOCH * 1000 - 1)),
+            None
+        );
+        assert_eq!(
+            to_ksuid(Ulid::min_for_timestamp(
+                (KSUID_EPOCH + u64::from(u32::MAX) + 1) * 1000
+            )),
+            None
+        );
+    }
+}
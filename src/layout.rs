@@ -0,0 +1,167 @@
+//! Description of how the 80 bits of the random part of a ULID are used.
+
+use crate::Ulid;
+
+/// Layout of the 80 bits of the random part of a ULID.
+///
+/// The random part is split into, from most to least significant bits,
+///
+/// - a node ID of `node_bits` bits, see [`Ulid::node_id`],
+/// - a sequence counter of `sequence_bits` bits, counting the ULIDs created
+///   by a node within the same millisecond,
+/// - the remaining [`random_bits`](Self::random_bits) bits of randomness.
+///
+/// The default layout uses all 80 bits for randomness, like the ULID spec.
+/// Fewer random bits trade collision resistance for traceability: the node ID
+/// tells which node created a ULID, the sequence how many ULIDs it created
+/// before in the same millisecond. [`NodeUlidGenerator::with_layout`] creates
+/// ULIDs following a layout.
+///
+/// [`NodeUlidGenerator::with_layout`]: crate::NodeUlidGenerator::with_layout
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::{EntropyLayout, Ulid};
+///
+/// const LAYOUT: EntropyLayout = EntropyLayout::new(16, 16);
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_002A_0003_35F1_ED78_7B67);
+///
+/// assert_eq!(LAYOUT.random_bits(), 48);
+/// assert_eq!(LAYOUT.node_id(&ulid), 42);
+/// assert_eq!(LAYOUT.sequence(&ulid), 3);
+/// assert_eq!(LAYOUT.random(&ulid), 0x35F1_ED78_7B67);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntropyLayout {
+    node_bits: u32,
+    sequence_bits: u32,
+}
+
+impl EntropyLayout {
+    /// Creates a new layout with a node ID of `node_bits` bits and a sequence
+    /// counter of `sequence_bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node_bits` or `sequence_bits` is larger than 32.
+    #[must_use]
+    pub const fn new(node_bits: u32, sequence_bits: u32) -> Self {
+        assert!(node_bits <= 32, "node ID can't have more than 32 bits");
+        assert!(
+            sequence_bits <= 32,
+            "sequence counter can't have more than 32 bits"
+        );
+
+        Self {
+            node_bits,
+            sequence_bits,
+        }
+    }
+
+    /// Returns the number of bits of the node ID.
+    #[must_use]
+    pub const fn node_bits(&self) -> u32 {
+        self.node_bits
+    }
+
+    /// Returns the number of bits of the sequence counter.
+    #[must_use]
+    pub const fn sequence_bits(&self) -> u32 {
+        self.sequence_bits
+    }
+
+    /// Returns the number of remaining random bits.
+    #[must_use]
+    pub const fn random_bits(&self) -> u32 {
+        80 - self.node_bits - self.sequence_bits
+    }
+
+    /// Returns the node ID of `ulid`.
+    #[must_use]
+    pub fn node_id(&self, ulid: &Ulid) -> u32 {
+        ulid.node_id(self.node_bits)
+    }
+
+    /// Returns the sequence counter of `ulid`.
+    #[must_use]
+    pub fn sequence(&self, ulid: &Ulid) -> u32 {
+        ((ulid.to_u128() >> self.random_bits()) & ((1 << self.sequence_bits) - 1)) as u32
+    }
+
+    /// Returns the remaining random bits of `ulid`.
+    #[must_use]
+    pub fn random(&self, ulid: &Ulid) -> u128 {
+        ulid.to_u128() & ((1 << self.random_bits()) - 1)
+    }
+
+    /// Returns the largest sequence counter of this layout.
+    #[must_use]
+    pub const fn max_sequence(&self) -> u32 {
+        ((1_u64 << self.sequence_bits) - 1) as u32
+    }
+
+    /// Returns `ulid` with the sequence counter replaced by `sequence`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sequence` is larger than [`max_sequence`](Self::max_sequence).
+    #[must_use]
+    pub fn with_sequence(&self, ulid: Ulid, sequence: u32) -> Ulid {
+        assert!(
+            sequence <= self.max_sequence(),
+            "sequence {sequence} doesn't fit into {} bits",
+            self.sequence_bits
+        );
+
+        let shift = self.random_bits();
+        let mask = ((1 << self.sequence_bits) - 1) << shift;
+        Ulid::from_u128((ulid.to_u128() & !mask) | (u128::from(sequence) << shift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components_cover_random_part() {
+        let layout = EntropyLayout::new(12, 20);
+        let ulid = Ulid::from(0x0162_A27A_F618_ABCD_EF01_2345_6789_ABCD);
+
+        assert_eq!(layout.node_id(&ulid), 0xABC);
+        assert_eq!(layout.sequence(&ulid), 0xDEF01);
+        assert_eq!(layout.random(&ulid), 0x2345_6789_ABCD);
+        assert_eq!(
+            layout.with_sequence(ulid, 0x12345),
+            Ulid::from(0x0162_A27A_F618_ABC1_2345_2345_6789_ABCD)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "sequence 4 doesn't fit into 2 bits")]
+    fn sequence_must_fit() {
+        let _ = EntropyLayout::new(0, 2).with_sequence(Ulid::from(0), 4);
+    }
+
+    #[test]
+    fn default_layout_is_all_random() {
+        let layout = EntropyLayout::default();
+        let ulid = Ulid::from(u128::MAX);
+
+        assert_eq!(layout.random_bits(), 80);
+        assert_eq!(layout.node_id(&ulid), 0);
+        assert_eq!(layout.sequence(&ulid), 0);
+        assert_eq!(layout.max_sequence(), 0);
+        assert_eq!(layout.random(&ulid), (1 << 80) - 1);
+    }
+
+    #[test]
+    fn widest_layout_leaves_random_bits() {
+        let layout = EntropyLayout::new(32, 32);
+
+        assert_eq!(layout.random_bits(), 16);
+        assert_eq!(layout.max_sequence(), u32::MAX);
+    }
+}
@@ -37,6 +37,7 @@
  * limitations under the License.
  */
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![doc(html_root_url = "https://docs.rs/rusty_ulid/2.0.0")]
 #![deny(
     anonymous_parameters,
@@ -176,28 +177,148 @@ use time::OffsetDateTime;
 #[cfg(feature = "chrono")]
 use chrono::prelude::{DateTime, TimeZone, Utc};
 
-use std::fmt;
-use std::str::FromStr;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Range, RangeInclusive};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::io;
 
 #[cfg(feature = "serde")]
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "async-graphql")]
+mod async_graphql_;
+#[cfg(feature = "portable-atomic")]
+mod atomic;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "bincode")]
+mod bincode_;
+#[cfg(feature = "borsh")]
+mod borsh_;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_;
+#[cfg(feature = "critical-section")]
+pub mod critical_section;
+#[cfg(any(
+    feature = "diesel-postgres",
+    feature = "diesel-mysql",
+    feature = "diesel-sqlite"
+))]
+mod diesel_;
+#[cfg(feature = "headers")]
+pub mod headers;
+#[cfg(feature = "http")]
+mod http_;
+#[cfg(feature = "ksuid")]
+pub mod ksuid;
+mod layout;
+#[cfg(feature = "log-kv")]
+mod log_;
+#[cfg(feature = "minicbor")]
+pub mod minicbor;
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+mod monotonic;
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+mod node;
+#[cfg(feature = "prost")]
+pub mod prost;
+#[cfg(feature = "rand09")]
+pub mod rand09;
+mod redacted;
+#[cfg(feature = "rkyv")]
+mod rkyv_;
 #[cfg(feature = "rocket")]
 mod rocket_;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_;
+#[cfg(feature = "parity-scale-codec")]
+mod scale_;
 #[cfg(feature = "schemars")]
 mod schemars;
+pub mod seeded;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(all(
+    feature = "tokio",
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+pub mod shared;
+#[cfg(feature = "slog")]
+mod slog_;
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+mod sqlx_;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(all(
+    feature = "tower",
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+pub mod tower;
+mod typed;
+#[cfg(feature = "ufmt")]
+mod ufmt_;
+#[cfg(feature = "valuable")]
+mod valuable_;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "zeroize")]
+mod zeroize_;
 
 /// Contains functions for encoding and decoding of
 /// [crockford Base32][crockford] strings.
 ///
 /// [crockford]: https://crockford.com/wrmg/base32.html
 pub mod crockford;
+#[cfg(feature = "portable-atomic")]
+pub use crate::atomic::AtomicUlidGenerator;
 pub use crate::crockford::DecodingError;
+pub use crate::layout::EntropyLayout;
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+pub use crate::monotonic::MonotonicUlids;
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
+pub use crate::node::NodeUlidGenerator;
+pub use crate::redacted::Redacted;
+pub use crate::seeded::SeededUlidGenerator;
+pub use crate::typed::TypedUlid;
 
 /// Returns the number of non-leap milliseconds since 1970-01-01T00:00:00Z
 /// (aka "UNIX timestamp").
-#[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
 fn unix_epoch_ms() -> u64 {
-    #[cfg(feature = "time")]
+    // std::time::SystemTime isn't available on wasm32-unknown-unknown
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    {
+        js_sys::Date::now() as u64
+    }
+    #[cfg(all(feature = "time", not(all(feature = "wasm", target_arch = "wasm32"))))]
     {
         let now = OffsetDateTime::now_utc();
 
@@ -223,7 +344,10 @@ fn unix_epoch_ms() -> u64 {
 /// // every ulid has exactly 26 characters
 /// assert_eq!(ulid_string.len(), 26);
 /// ```
-#[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
 #[must_use]
 pub fn generate_ulid_string() -> String {
     Ulid::generate().to_string()
@@ -241,16 +365,144 @@ pub fn generate_ulid_string() -> String {
 /// // a binary ulid has exactly 16 bytes
 /// assert_eq!(ulid_bytes.len(), 16);
 /// ```
-#[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+#[cfg(all(
+    any(feature = "rand", feature = "rand09", feature = "getrandom"),
+    any(feature = "chrono", feature = "time")
+))]
 #[must_use]
 pub fn generate_ulid_bytes() -> [u8; 16] {
     Ulid::generate().into()
 }
 
-#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+/// Returns the random fingerprint of the current process.
+///
+/// The fingerprint is generated once, on first use, and stays the same for
+/// the lifetime of the process. See [`Ulid::fingerprint`] for details.
+///
+/// # Example
+/// ```
+/// # use rusty_ulid::process_fingerprint;
+/// assert_eq!(process_fingerprint(), process_fingerprint());
+/// ```
+#[cfg(any(feature = "rand", feature = "rand09", feature = "getrandom"))]
+#[must_use]
+pub fn process_fingerprint() -> u16 {
+    static PROCESS_FINGERPRINT: std::sync::OnceLock<u16> = std::sync::OnceLock::new();
+
+    *PROCESS_FINGERPRINT.get_or_init(|| random_bits().0)
+}
+
+/// Returns the 80 random bits of a new ULID from the thread-local generator of
+/// `rand` 0.8, or of `rand` 0.9 if only the `rand09` feature is enabled, or
+/// straight from the operating system if only the `getrandom` feature is enabled.
+#[cfg(feature = "rand")]
+fn random_bits() -> (u16, u64) {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    (rng.gen(), rng.gen())
+}
+
+#[cfg(all(feature = "rand09", not(feature = "rand")))]
+fn random_bits() -> (u16, u64) {
+    ::rand09::random()
+}
+
+#[cfg(all(feature = "getrandom", not(any(feature = "rand", feature = "rand09"))))]
+fn random_bits() -> (u16, u64) {
+    let mut bytes = [0u8; 10];
+    fill_random_bytes(&mut bytes);
+
+    let (high, low) = bytes.split_at(2);
+    (
+        u16::from_be_bytes(high.try_into().unwrap()),
+        u64::from_be_bytes(low.try_into().unwrap()),
+    )
+}
+
+/// Fills `bytes` from the same source of randomness as `random_bits`.
+#[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+fn fill_random_bytes(bytes: &mut [u8]) {
+    use rand::RngCore;
+
+    rand::thread_rng().fill_bytes(bytes);
+}
+
+#[cfg(all(
+    feature = "rand09",
+    not(feature = "rand"),
+    any(feature = "chrono", feature = "time")
+))]
+fn fill_random_bytes(bytes: &mut [u8]) {
+    use ::rand09::RngCore;
+
+    ::rand09::rng().fill_bytes(bytes);
+}
+
+#[cfg(all(feature = "getrandom", not(any(feature = "rand", feature = "rand09"))))]
+fn fill_random_bytes(bytes: &mut [u8]) {
+    getrandom::getrandom(bytes).expect("operating system random number generator failed");
+}
+
+// version (4 bits) and variant (2 bits) of a UUID
+const UUID_VERSION_VARIANT_MASK: u128 = 0x0000_0000_0000_F000_C000_0000_0000_0000;
+// version 7, RFC 9562 variant
+const UUID_V7_VERSION_VARIANT: u128 = 0x0000_0000_0000_7000_8000_0000_0000_0000;
+// the 12 bits of rand_a and the 62 bits of rand_b of a UUIDv7
+const UUID_V7_RAND_A_MASK: u128 = 0x0000_0000_0000_0FFF_0000_0000_0000_0000;
+const UUID_V7_RAND_B_MASK: u128 = 0x0000_0000_0000_0000_3FFF_FFFF_FFFF_FFFF;
+// random bits of a ULID that don't fit into a UUIDv7
+const UUID_V7_DROPPED_BITS: u32 = 6;
+
+// the 48 bits of the timestamp and the 80 bits of the random part
+const TIMESTAMP_PART_MASK: u128 = 0xFFFF_FFFF_FFFF_0000_0000_0000_0000_0000;
+const RANDOM_PART_MASK: u128 = !TIMESTAMP_PART_MASK;
+
+#[cfg(feature = "std")]
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// prefix of the URN representation of a ULID
+const URN_PREFIX: &str = "ulid:";
+
+/// Returns the position of the first lowercase letter or alias `I`, `L` or `O`
+/// of the valid ULID string `input`, or `None` if it's canonical.
+fn non_canonical_position(input: &str) -> Option<usize> {
+    // valid, so every byte is an ASCII symbol of the alphabet or an alias
+    input
+        .bytes()
+        .position(|byte| byte.is_ascii_lowercase() || matches!(byte, b'I' | b'L' | b'O'))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(compare(PartialEq)),
+    archive_attr(derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    any(
+        feature = "diesel-postgres",
+        feature = "diesel-mysql",
+        feature = "diesel-sqlite"
+    ),
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Binary)
+)]
+#[cfg_attr(
+    feature = "diesel-postgres",
+    diesel(sql_type = diesel::sql_types::Uuid)
+)]
+#[repr(transparent)]
 /// The ULID data type.
+///
+/// A ULID is stored as its 16 bytes in big-endian byte order, which can be
+/// borrowed by [`as_bytes`](Self::as_bytes) without copying. All arithmetic is
+/// done on the equivalent `u128`.
 pub struct Ulid {
-    value: (u64, u64),
+    bytes: [u8; 16],
 }
 
 impl Ulid {
@@ -273,10 +525,159 @@ impl Ulid {
     /// # Panics
     ///
     /// Panics if called after `+10889-08-02T05:31:50.655Z`.
-    #[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
     #[must_use]
     pub fn generate() -> Self {
-        Self::from_timestamp_with_rng(unix_epoch_ms(), &mut rand::thread_rng())
+        Self::from_timestamp_and_random_bits(unix_epoch_ms(), random_bits())
+    }
+
+    /// Creates a new ULID with the given `timestamp` in milliseconds since the UNIX epoch.
+    ///
+    /// This is useful for backfilling historical records. Unlike
+    /// [`from_timestamp_with_rng`](Self::from_timestamp_with_rng), the random
+    /// bits are obtained like the ones of [`generate`](Self::generate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::generate_with_timestamp(1_523_144_390_168);
+    ///
+    /// assert_eq!(ulid.timestamp(), 1_523_144_390_168);
+    /// assert!(ulid.to_string().starts_with("01CAH7NXGR"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+    #[cfg(any(feature = "rand", feature = "rand09", feature = "getrandom"))]
+    #[must_use]
+    pub fn generate_with_timestamp(timestamp: u64) -> Self {
+        Self::from_timestamp_and_random_bits(timestamp, random_bits())
+    }
+
+    /// Creates a new ULID with the current timestamp of the given `clock`.
+    ///
+    /// The random bits are obtained like the ones of [`generate`](Self::generate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::test_util::SteppingClock;
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let clock = SteppingClock::new(1_523_144_390_168, 1);
+    ///
+    /// let first = Ulid::generate_with_clock(&clock);
+    /// let second = Ulid::generate_with_clock(&clock);
+    ///
+    /// assert_eq!(first.timestamp(), 1_523_144_390_168);
+    /// assert_eq!(second.timestamp(), 1_523_144_390_169);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp of `clock` is larger than `0xFFFF_FFFF_FFFF`.
+    #[cfg(all(
+        feature = "test-util",
+        any(feature = "rand", feature = "rand09", feature = "getrandom")
+    ))]
+    #[must_use]
+    pub fn generate_with_clock<C>(clock: &C) -> Self
+    where
+        C: test_util::Clock + ?Sized,
+    {
+        Self::generate_with_timestamp(clock.timestamp())
+    }
+
+    /// Creates `n` new ULIDs sharing the same timestamp.
+    ///
+    /// The current time is obtained only once and the random bits of all ULIDs
+    /// are filled in bulk. The returned ULIDs are strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulids = Ulid::generate_batch(1_000);
+    ///
+    /// assert_eq!(ulids.len(), 1_000);
+    /// assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn generate_batch(n: usize) -> Vec<Self> {
+        let mut ulids = Vec::with_capacity(n);
+        Self::generate_batch_into(&mut ulids, n);
+        ulids
+    }
+
+    /// Appends `n` new ULIDs sharing the same timestamp to `ulids`.
+    ///
+    /// This is the allocation-reusing variant of
+    /// [`generate_batch`](Self::generate_batch). Existing elements of `ulids` are
+    /// left untouched, only the appended ULIDs are strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let mut ulids = Vec::new();
+    /// Ulid::generate_batch_into(&mut ulids, 10);
+    /// Ulid::generate_batch_into(&mut ulids, 10);
+    ///
+    /// assert_eq!(ulids.len(), 20);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    pub fn generate_batch_into(ulids: &mut Vec<Self>, n: usize) {
+        if n == 0 {
+            return;
+        }
+
+        let timestamp = unix_epoch_ms();
+        let mut bytes = vec![0u8; n * 10];
+        fill_random_bytes(&mut bytes);
+
+        let start = ulids.len();
+        ulids.extend(bytes.chunks_exact(10).map(|chunk| {
+            let (high, low) = chunk.split_at(2);
+            let random = (
+                u16::from_be_bytes(high.try_into().unwrap()),
+                u64::from_be_bytes(low.try_into().unwrap()),
+            );
+            Self::from_timestamp_and_random_bits(timestamp, random)
+        }));
+
+        let batch = &mut ulids[start..];
+        batch.sort_unstable();
+        for i in 1..batch.len() {
+            if batch[i] <= batch[i - 1] {
+                // duplicate random bits, or the random part of the previous
+                // ULID was exhausted and the next millisecond got borrowed
+                batch[i] =
+                    Self::next_strictly_increasing(Some(batch[i - 1]), timestamp, random_bits);
+            }
+        }
     }
 
     /// Creates the next monotonic ULID for the given `previous_ulid`.
@@ -298,13 +699,17 @@ impl Ulid {
     /// # Panics
     ///
     /// Panics if called after `+10889-08-02T05:31:50.655Z`.
-    #[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
     #[must_use]
     pub fn next_monotonic(previous_ulid: Self) -> Self {
-        Self::next_monotonic_from_timestamp_with_rng(
-            previous_ulid,
+        Self::next_monotonic_from_timestamp_and_random_bits(
+            Some(previous_ulid),
             unix_epoch_ms(),
-            &mut rand::thread_rng(),
+            random_bits,
+            None,
         )
     }
 
@@ -328,13 +733,169 @@ impl Ulid {
     /// # Panics
     ///
     /// Panics if called after `+10889-08-02T05:31:50.655Z`.
-    #[cfg(all(feature = "rand", any(feature = "chrono", feature = "time")))]
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
     #[must_use]
     pub fn next_strictly_monotonic(previous_ulid: Self) -> Option<Self> {
-        Self::next_strictly_monotonic_from_timestamp_with_rng(
-            previous_ulid,
+        let result = Self::next_monotonic(previous_ulid);
+
+        if previous_ulid < result {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Creates the next strictly monotonic ULID for the given `previous_ulid`,
+    /// waiting for the next millisecond if necessary.
+    ///
+    /// Unlike [`next_monotonic`](Self::next_monotonic) and
+    /// [`next_strictly_monotonic`](Self::next_strictly_monotonic), this function
+    /// handles an overflow of the random part of `previous_ulid` by blocking the
+    /// current thread until the clock has passed the timestamp of
+    /// `previous_ulid`. The returned ULID is always strictly larger than
+    /// `previous_ulid` and never carries a timestamp from the future.
+    ///
+    /// If the clock moved backwards, `previous_ulid` is incremented instead.
+    /// Only if its random part is exhausted as well, this function blocks until
+    /// the clock caught up again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let mut previous_ulid = Ulid::generate();
+    /// for _ in 0..1000 {
+    ///     let ulid = Ulid::next_monotonic_waiting(previous_ulid);
+    ///     assert!(previous_ulid < ulid);
+    ///     previous_ulid = ulid;
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn next_monotonic_waiting(previous_ulid: Self) -> Self {
+        monotonic::next_waiting(previous_ulid)
+    }
+
+    /// Creates a new ULID that is strictly larger than the previous one created
+    /// by this function on the current thread.
+    ///
+    /// Each thread keeps its own monotonic state, so no synchronization between
+    /// threads is necessary. ULIDs created on different threads are not ordered
+    /// with respect to each other. See [`MonotonicUlids`] for the handling of
+    /// overflows and a clock moving backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let first = Ulid::generate_monotonic_thread_local();
+    /// let second = Ulid::generate_monotonic_thread_local();
+    ///
+    /// assert!(first < second);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn generate_monotonic_thread_local() -> Self {
+        monotonic::next_thread_local()
+    }
+
+    /// Returns an endless iterator of strictly increasing ULIDs.
+    ///
+    /// See [`MonotonicUlids`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let fixtures: Vec<Ulid> = Ulid::iter_monotonic().take(100).collect();
+    ///
+    /// assert!(fixtures.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub const fn iter_monotonic() -> MonotonicUlids {
+        MonotonicUlids::new()
+    }
+
+    /// Creates a new ULID carrying the [`process_fingerprint`].
+    ///
+    /// See [`fingerprint`](Self::fingerprint) for the entropy trade-off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{process_fingerprint, Ulid};
+    ///
+    /// let ulid = Ulid::generate_with_process_fingerprint();
+    ///
+    /// assert_eq!(ulid.fingerprint(), process_fingerprint());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn generate_with_process_fingerprint() -> Self {
+        Self::generate().with_fingerprint(process_fingerprint())
+    }
+
+    /// Creates the next monotonic ULID for the given `previous_ulid`, carrying the
+    /// [`process_fingerprint`] if a new ULID is created instead of simply
+    /// incrementing `previous_ulid`.
+    ///
+    /// See [`fingerprint`](Self::fingerprint) for the entropy trade-off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{process_fingerprint, Ulid};
+    ///
+    /// let ulid = Ulid::next_monotonic_with_process_fingerprint(Ulid::from(0));
+    ///
+    /// assert_eq!(ulid.fingerprint(), process_fingerprint());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn next_monotonic_with_process_fingerprint(previous_ulid: Self) -> Self {
+        Self::next_monotonic_from_timestamp_and_random_bits(
+            Some(previous_ulid),
             unix_epoch_ms(),
-            &mut rand::thread_rng(),
+            random_bits,
+            Some(&|ulid: Self| ulid.with_fingerprint(process_fingerprint())),
         )
     }
 
@@ -361,15 +922,160 @@ impl Ulid {
     where
         R: rand::Rng,
     {
+        Self::from_timestamp_and_random_bits(timestamp, (rng.gen(), rng.gen()))
+    }
+
+    /// Creates a new ULID with the given `timestamp` and the 80 random bits of `random`.
+    ///
+    /// This is the common core of the `from_timestamp_with_rng` functions of all
+    /// supported sources of randomness and of the `SeededUlidGenerator`.
+    pub(crate) fn from_timestamp_and_random_bits(timestamp: u64, random: (u16, u64)) -> Self {
         if (timestamp & 0xFFFF_0000_0000_0000) != 0 {
             panic!("ULID does not support timestamps after +10889-08-02T05:31:50.655Z");
         }
 
-        let high = (timestamp << 16) | u64::from(rng.gen::<u16>());
-        let low = rng.gen::<u64>();
-        let value = (high, low);
+        Self::from_u128(
+            (u128::from(timestamp) << 80) | (u128::from(random.0) << 64) | u128::from(random.1),
+        )
+    }
+
+    /// Returns the next ULID for `timestamp` that is strictly larger than
+    /// `previous_ulid`, calling `random` only if a new ULID has to be created.
+    ///
+    /// If the random part of `previous_ulid` is exhausted, the next ULID borrows
+    /// the following millisecond. If the clock moved backwards, `previous_ulid` is
+    /// incremented instead.
+    ///
+    /// This is the common core of all strictly increasing generators.
+    #[cfg(any(
+        feature = "critical-section",
+        feature = "portable-atomic",
+        all(
+            any(feature = "rand", feature = "rand09", feature = "getrandom"),
+            any(feature = "chrono", feature = "time")
+        )
+    ))]
+    pub(crate) fn next_strictly_increasing(
+        previous_ulid: Option<Self>,
+        timestamp: u64,
+        random: impl FnOnce() -> (u16, u64),
+    ) -> Self {
+        let previous_ulid = match previous_ulid {
+            Some(previous_ulid) if timestamp <= previous_ulid.timestamp() => previous_ulid,
+            _ => return Self::from_timestamp_and_random_bits(timestamp, random()),
+        };
+
+        #[cfg(feature = "tracing")]
+        if timestamp < previous_ulid.timestamp() {
+            tracing::debug!(
+                previous_timestamp = previous_ulid.timestamp(),
+                timestamp,
+                "clock moved backwards since previous ULID"
+            );
+        }
+
+        let incremented = previous_ulid.increment();
+        if previous_ulid < incremented {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(timestamp, "incremented previous ULID");
+            return incremented;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            timestamp,
+            "random part of ULID overflowed, borrowing the next millisecond"
+        );
+        Self::from_timestamp_and_random_bits(previous_ulid.timestamp() + 1, random())
+    }
+
+    /// Splits the 80 bits of `random` like the random part of a ULID.
+    #[cfg(any(feature = "critical-section", feature = "portable-atomic"))]
+    pub(crate) fn split_random_bits(random: [u8; 10]) -> (u16, u64) {
+        (
+            u16::from_be_bytes([random[0], random[1]]),
+            u64::from_be_bytes([
+                random[2], random[3], random[4], random[5], random[6], random[7], random[8],
+                random[9],
+            ]),
+        )
+    }
+
+    /// Creates a ULID derived from `namespace` and `name`, similar to a version 5 UUID.
+    ///
+    /// The timestamp is the one of `namespace`, the 80 random bits are the first
+    /// 10 bytes of the SHA-256 hash of the 16 bytes of `namespace` followed by
+    /// `name`. Calling this function with the same arguments always returns the
+    /// same ULID.
+    ///
+    /// Use [`new_deterministic_with_timestamp`](Self::new_deterministic_with_timestamp)
+    /// if the ULIDs should carry a timestamp of their own, e.g. the creation
+    /// time of the record identified by `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let namespace = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// let ulid = Ulid::new_deterministic(namespace, b"order-4711");
+    ///
+    /// assert_eq!(ulid, Ulid::new_deterministic(namespace, b"order-4711"));
+    /// assert_ne!(ulid, Ulid::new_deterministic(namespace, b"order-4712"));
+    /// assert_eq!(ulid.timestamp(), namespace.timestamp());
+    /// assert_eq!(ulid.to_string(), "01CAH7NXGRE2CDG2KMCJ2TNCVC");
+    /// ```
+    #[cfg(feature = "deterministic")]
+    #[must_use]
+    pub fn new_deterministic(namespace: Self, name: &[u8]) -> Self {
+        Self::new_deterministic_with_timestamp(namespace.timestamp(), namespace, name)
+    }
+
+    /// Creates a ULID with the given `timestamp` derived from `namespace` and `name`.
+    ///
+    /// The 80 random bits are calculated like the ones of
+    /// [`new_deterministic`](Self::new_deterministic) and don't depend on
+    /// `timestamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let namespace = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// let ulid = Ulid::new_deterministic_with_timestamp(1_700_000_000_000, namespace, b"order-4711");
+    ///
+    /// assert_eq!(ulid.timestamp(), 1_700_000_000_000);
+    /// assert_eq!(
+    ///     ulid.to_string()[10..],
+    ///     Ulid::new_deterministic(namespace, b"order-4711").to_string()[10..]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+    #[cfg(feature = "deterministic")]
+    #[must_use]
+    pub fn new_deterministic_with_timestamp(timestamp: u64, namespace: Self, name: &[u8]) -> Self {
+        use sha2::{Digest, Sha256};
+
+        let hash = Sha256::new()
+            .chain_update(namespace.bytes)
+            .chain_update(name)
+            .finalize();
 
-        Self { value }
+        Self::from_timestamp_and_random_bits(
+            timestamp,
+            (
+                u16::from_be_bytes([hash[0], hash[1]]),
+                u64::from_be_bytes([
+                    hash[2], hash[3], hash[4], hash[5], hash[6], hash[7], hash[8], hash[9],
+                ]),
+            ),
+        )
     }
 
     /// Creates the next monotonic ULID with the given `previous_ulid`, `timestamp`
@@ -436,6 +1142,10 @@ impl Ulid {
     /// If the random part of `previous_ulid` would overflow, this function returns a ULID with
     /// the random part set to zero.
     ///
+    /// With the `tracing` feature enabled, same-millisecond increments are reported as
+    /// `trace` events while overflows and a `timestamp` older than `previous_ulid` are
+    /// reported as `debug` events.
+    ///
     /// # Examples
     ///
     /// ```
@@ -508,13 +1218,57 @@ impl Ulid {
     where
         R: rand::Rng,
     {
-        if let Some(previous_ulid) = previous_ulid {
-            if previous_ulid.timestamp() == timestamp {
-                return previous_ulid.increment();
-            }
-        }
+        Self::next_monotonic_from_timestamp_and_random_bits(
+            previous_ulid,
+            timestamp,
+            || (rng.gen(), rng.gen()),
+            postprocessor,
+        )
+    }
+
+    /// Creates the next monotonic ULID with the given `previous_ulid` and `timestamp`,
+    /// calling `random` only if a new ULID has to be created.
+    ///
+    /// This is the common core of the monotonic functions of all supported sources
+    /// of randomness.
+    #[cfg(any(
+        feature = "rand",
+        feature = "rand09",
+        all(feature = "getrandom", any(feature = "chrono", feature = "time"))
+    ))]
+    pub(crate) fn next_monotonic_from_timestamp_and_random_bits(
+        previous_ulid: Option<Self>,
+        timestamp: u64,
+        random: impl FnOnce() -> (u16, u64),
+        postprocessor: Option<&dyn Fn(Self) -> Self>,
+    ) -> Self {
+        if let Some(previous_ulid) = previous_ulid {
+            let previous_timestamp = previous_ulid.timestamp();
+            if previous_timestamp == timestamp {
+                let result = previous_ulid.increment();
+                #[cfg(feature = "tracing")]
+                if result < previous_ulid {
+                    tracing::debug!(
+                        timestamp,
+                        "random part of ULID overflowed within the same millisecond"
+                    );
+                } else {
+                    tracing::trace!(timestamp, "incremented ULID within the same millisecond");
+                }
+                return result;
+            }
 
-        let result = Self::from_timestamp_with_rng(timestamp, rng);
+            #[cfg(feature = "tracing")]
+            if timestamp < previous_timestamp {
+                tracing::debug!(
+                    previous_timestamp,
+                    timestamp,
+                    "clock moved backwards since previous ULID"
+                );
+            }
+        }
+
+        let result = Self::from_timestamp_and_random_bits(timestamp, random());
         postprocessor.map_or(result, |postprocessor| postprocessor(result))
     }
 
@@ -704,7 +1458,7 @@ impl Ulid {
     /// ```
     #[must_use]
     pub fn timestamp(&self) -> u64 {
-        self.value.0 >> 16
+        (self.to_u128() >> 80) as u64
     }
 
     /// Returns the timestamp of this ULID as a `DateTime<Utc>`.
@@ -753,517 +1507,2392 @@ impl Ulid {
             .expect("invalid or out-of-range datetime")
     }
 
-    /// Returns a new ULID with the random part incremented by one.
-    ///
-    /// Overflowing the random part resets it to zero without influencing
-    /// the timestamp.
+    /// Compares the timestamps of this ULID and `other`, ignoring their random parts.
     ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
+    /// use std::cmp::Ordering;
     ///
-    /// let ulid = Ulid::from(0);
-    /// let incremented = ulid.increment();
+    /// let ulid = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
+    /// let other = Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0000);
     ///
-    /// assert_eq!(incremented, Ulid::from(1));
+    /// assert_eq!(ulid.cmp(&other), Ordering::Greater);
+    /// assert_eq!(ulid.cmp_timestamp(&other), Ordering::Equal);
     /// ```
+    #[must_use]
+    pub fn cmp_timestamp(&self, other: &Self) -> Ordering {
+        self.bytes[..6].cmp(&other.bytes[..6])
+    }
+
+    /// Returns `true` if this ULID and `other` were created in the same millisecond,
+    /// i.e. if their timestamps are equal.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFE);
-    /// let incremented = ulid.increment();
+    /// let ulid = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
     ///
-    /// assert_eq!(incremented, Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF));
+    /// assert!(ulid.same_millisecond(&Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0000)));
+    /// assert!(!ulid.same_millisecond(&Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0000)));
     /// ```
+    #[must_use]
+    pub fn same_millisecond(&self, other: &Self) -> bool {
+        self.bytes[..6] == other.bytes[..6]
+    }
+
+    /// Returns the smallest ULID with the given `timestamp`, i.e. the one with
+    /// a random part of all zeros.
+    ///
+    /// Together with [`max_for_timestamp`](Self::max_for_timestamp), this can be used
+    /// for inclusive range scans over ULID keys.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF);
-    /// let incremented = ulid.increment();
+    /// let min = Ulid::min_for_timestamp(1_523_144_390_168);
     ///
-    /// assert_eq!(incremented, Ulid::from(0));
+    /// assert_eq!(min.to_string(), "01CAH7NXGR0000000000000000");
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
     #[must_use]
-    pub fn increment(self) -> Self {
-        const TIMESTAMP_PART_MASK: u128 = 0xFFFF_FFFF_FFFF_0000_0000_0000_0000_0000;
-        const RANDOM_PART_MASK: u128 = !TIMESTAMP_PART_MASK;
-
-        let value: u128 = self.into();
-
-        if value & RANDOM_PART_MASK == RANDOM_PART_MASK {
-            // overflow, set random part to zero
-            (value & TIMESTAMP_PART_MASK).into()
-        } else {
-            (value + 1).into()
+    pub const fn min_for_timestamp(timestamp: u64) -> Self {
+        if (timestamp & 0xFFFF_0000_0000_0000) != 0 {
+            panic!("ULID does not support timestamps after +10889-08-02T05:31:50.655Z");
         }
+
+        Self::from_u128((timestamp as u128) << 80)
     }
 
-    /// Returns the string representaton of this ULID.
+    /// Returns the largest ULID with the given `timestamp`, i.e. the one with
+    /// a random part of all ones.
     ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0);
+    /// let max = Ulid::max_for_timestamp(1_523_144_390_168);
     ///
-    /// assert_eq!(ulid.to_string(), "00000000000000000000000000");
+    /// assert_eq!(max.to_string(), "01CAH7NXGRZZZZZZZZZZZZZZZZ");
     /// ```
     ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+    #[must_use]
+    pub const fn max_for_timestamp(timestamp: u64) -> Self {
+        if (timestamp & 0xFFFF_0000_0000_0000) != 0 {
+            panic!("ULID does not support timestamps after +10889-08-02T05:31:50.655Z");
+        }
+
+        Self::from_u128(((timestamp as u128) << 80) | RANDOM_PART_MASK)
+    }
+
+    /// Returns the inclusive range of all ULIDs created within the given range of
+    /// `timestamps` in milliseconds since the UNIX epoch.
+    ///
+    /// The `end` of `timestamps` is exclusive while the end of the resulting ULID
+    /// range is inclusive: it is the largest ULID of the last millisecond, so the
+    /// result can be used directly for key-range scans. Timestamps larger than `0xFFFF_FFFF_FFFF` are clamped to the largest
+    /// ULID timestamp. An empty range of timestamps results in an empty range.
+    ///
+    /// # Examples
+    ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF);
+    /// let range = Ulid::range_for(1_523_144_390_168..1_523_144_390_170);
     ///
-    /// assert_eq!(ulid.to_string(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    /// assert_eq!(range.start().to_string(), "01CAH7NXGR0000000000000000");
+    /// assert_eq!(range.end().to_string(), "01CAH7NXGSZZZZZZZZZZZZZZZZ");
+    /// assert!(range.contains(&Ulid::min_for_timestamp(1_523_144_390_169)));
+    ///
+    /// assert!(Ulid::range_for(1_523_144_390_168..1_523_144_390_168).is_empty());
     /// ```
-    #[allow(clippy::inherent_to_string_shadow_display)]
-    // impl fmt::Display is using this method
-    // https://github.com/rust-lang/rust-clippy/issues/4396
-    #[allow(clippy::wrong_self_convention)]
-    // 1.53.0 still requires clippy::wrong_self_convention
-    // 1.54.0-beta fixes this false positive
     #[must_use]
-    pub fn to_string(&self) -> String {
-        let mut string = String::with_capacity(26);
+    pub fn range_for(timestamps: Range<u64>) -> RangeInclusive<Self> {
+        const END: u64 = 0x0001_0000_0000_0000;
 
-        crockford::append_crockford_u64_tuple(self.value, &mut string);
-
-        string
-    }
-}
-
-impl fmt::Display for Ulid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        f.write_str(&self.to_string())
-    }
-}
-
-impl FromStr for Ulid {
-    type Err = DecodingError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let value = crockford::parse_crockford_u64_tuple(s)?;
+        let start = timestamps.start.min(END);
+        let end = timestamps.end.min(END);
+        if start >= end {
+            return Self::from_u128(u128::MAX)..=Self::from_u128(0);
+        }
 
-        Ok(Self { value })
+        Self::min_for_timestamp(start)..=Self::max_for_timestamp(end - 1)
     }
-}
 
-impl From<[u8; 16]> for Ulid {
+    /// Returns the inclusive range of all ULIDs created within the given range of
+    /// `datetimes`, see [`range_for`](Self::range_for).
+    ///
+    /// The start is rounded down and the end is rounded up to full milliseconds.
+    /// Datetimes before the UNIX epoch are clamped to it.
+    ///
     /// # Examples
     ///
     /// ```
+    /// use chrono::{TimeZone, Utc};
     /// use rusty_ulid::Ulid;
     ///
-    /// let bytes: [u8; 16] = [
-    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
-    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
-    /// ];
-    ///
-    /// let ulid = Ulid::from(bytes);
+    /// let tuesday = Utc.with_ymd_and_hms(2018, 4, 3, 0, 0, 0).unwrap();
+    /// let wednesday = Utc.with_ymd_and_hms(2018, 4, 4, 0, 0, 0).unwrap();
     ///
-    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let range = Ulid::range_for_datetime(tuesday..wednesday);
     ///
-    /// assert_eq!(ulid, expected_ulid);
+    /// assert_eq!(range.start().datetime(), tuesday);
+    /// assert_eq!(range.end().timestamp() + 1, wednesday.timestamp_millis() as u64);
     /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn range_for_datetime(datetimes: Range<DateTime<Utc>>) -> RangeInclusive<Self> {
+        let nanos = |datetime: DateTime<Utc>| {
+            i128::from(datetime.timestamp()) * 1_000_000_000
+                + i128::from(datetime.timestamp_subsec_nanos())
+        };
+
+        Self::range_for_nanos(nanos(datetimes.start)..nanos(datetimes.end))
+    }
+
+    /// Returns the inclusive range of all ULIDs created within the given range of
+    /// `datetimes`, see [`range_for`](Self::range_for).
+    ///
+    /// The start is rounded down and the end is rounded up to full milliseconds.
+    /// Datetimes before the UNIX epoch are clamped to it.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
+    /// use time::OffsetDateTime;
     ///
-    /// let bytes: [u8; 16] = [
-    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
-    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
-    /// ];
-    ///
-    /// let ulid : Ulid = bytes.into();
+    /// // 2018-04-03 and 2018-04-04, 00:00 UTC
+    /// let tuesday = OffsetDateTime::from_unix_timestamp(1_522_713_600)?;
+    /// let wednesday = OffsetDateTime::from_unix_timestamp(1_522_800_000)?;
     ///
-    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let range = Ulid::range_for_offsetdatetime(tuesday..wednesday);
     ///
-    /// assert_eq!(ulid, expected_ulid);
+    /// assert_eq!(range.start().offsetdatetime(), tuesday);
+    /// assert_eq!(range.end().offsetdatetime(), wednesday - time::Duration::MILLISECOND);
+    /// # Ok::<(), time::error::ComponentRange>(())
     /// ```
+    #[cfg(feature = "time")]
     #[must_use]
-    fn from(bytes: [u8; 16]) -> Self {
-        #[rustfmt::skip]
-        let high = (u64::from(bytes[0]) << 56)
-            | (u64::from(bytes[1]) << 48)
-            | (u64::from(bytes[2]) << 40)
-            | (u64::from(bytes[3]) << 32)
-            | (u64::from(bytes[4]) << 24)
-            | (u64::from(bytes[5]) << 16)
-            | (u64::from(bytes[6]) << 8)
-            | u64::from(bytes[7]);
-
-        #[rustfmt::skip]
-        let low = (u64::from(bytes[8]) << 56)
-            | (u64::from(bytes[9]) << 48)
-            | (u64::from(bytes[10]) << 40)
-            | (u64::from(bytes[11]) << 32)
-            | (u64::from(bytes[12]) << 24)
-            | (u64::from(bytes[13]) << 16)
-            | (u64::from(bytes[14]) << 8)
-            | u64::from(bytes[15]);
-
-        let value = (high, low);
-        Self { value }
+    pub fn range_for_offsetdatetime(datetimes: Range<OffsetDateTime>) -> RangeInclusive<Self> {
+        Self::range_for_nanos(
+            datetimes.start.unix_timestamp_nanos()..datetimes.end.unix_timestamp_nanos(),
+        )
     }
-}
 
-impl From<Ulid> for [u8; 16] {
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn range_for_nanos(nanos: Range<i128>) -> RangeInclusive<Self> {
+        let millis = |nanos: i128| u64::try_from(nanos.max(0)).unwrap_or(u64::MAX);
+
+        let start = millis(nanos.start.div_euclid(1_000_000));
+        let end = millis((nanos.end + 999_999).div_euclid(1_000_000));
+        Self::range_for(start..end)
+    }
+
+    /// Returns a new ULID with the random part incremented by one.
+    ///
+    /// Overflowing the random part resets it to zero without influencing
+    /// the timestamp.
+    ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
-    ///
-    /// let bytes = <[u8; 16]>::from(ulid);
-    ///
-    /// let expected_bytes: [u8; 16] = [
-    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
-    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
-    /// ];
+    /// let ulid = Ulid::from(0);
+    /// let incremented = ulid.increment();
     ///
-    /// assert_eq!(bytes, expected_bytes);
+    /// assert_eq!(incremented, Ulid::from(1));
     /// ```
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let ulid = Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFE);
+    /// let incremented = ulid.increment();
     ///
-    /// let bytes: [u8; 16] = ulid.into();
+    /// assert_eq!(incremented, Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF));
+    /// ```
     ///
-    /// let expected_bytes: [u8; 16] = [
-    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
-    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
-    /// ];
+    /// ```
+    /// use rusty_ulid::Ulid;
     ///
-    /// assert_eq!(bytes, expected_bytes);
+    /// let ulid = Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF);
+    /// let incremented = ulid.increment();
+    ///
+    /// assert_eq!(incremented, Ulid::from(0));
     /// ```
-    #[rustfmt::skip]
     #[must_use]
-    fn from(ulid: Ulid) -> Self {
-        let value = ulid.value;
-
-        [
-            ((value.0 >> 56) & 0xFF) as u8,
-            ((value.0 >> 48) & 0xFF) as u8,
-            ((value.0 >> 40) & 0xFF) as u8,
-            ((value.0 >> 32) & 0xFF) as u8,
-            ((value.0 >> 24) & 0xFF) as u8,
-            ((value.0 >> 16) & 0xFF) as u8,
-            ((value.0 >> 8) & 0xFF) as u8,
-            (value.0 & 0xFF) as u8,
-            ((value.1 >> 56) & 0xFF) as u8,
-            ((value.1 >> 48) & 0xFF) as u8,
-            ((value.1 >> 40) & 0xFF) as u8,
-            ((value.1 >> 32) & 0xFF) as u8,
-            ((value.1 >> 24) & 0xFF) as u8,
-            ((value.1 >> 16) & 0xFF) as u8,
-            ((value.1 >> 8) & 0xFF) as u8,
-            (value.1 & 0xFF) as u8,
-        ]
+    pub fn increment(self) -> Self {
+        let value = self.to_u128();
+
+        if value & RANDOM_PART_MASK == RANDOM_PART_MASK {
+            // overflow, set random part to zero
+            Self::from_u128(value & TIMESTAMP_PART_MASK)
+        } else {
+            Self::from_u128(value + 1)
+        }
     }
-}
 
-impl From<(u64, u64)> for Ulid {
+    /// Returns the fingerprint stored in this ULID.
+    ///
+    /// The fingerprint occupies the 16 most significant bits of the random part,
+    /// directly following the timestamp. ULIDs created by
+    /// [`generate_with_process_fingerprint`](Self::generate_with_process_fingerprint)
+    /// carry the [`process_fingerprint`](crate::process_fingerprint), which makes it possible
+    /// to attribute them to the process that created them, e.g. while debugging.
+    ///
+    /// This comes at the cost of entropy: only 64 of the 80 bits of the random part
+    /// remain random, and all ULIDs of a process share the same fingerprint.
+    /// Incrementing a ULID may carry into the fingerprint once the lower 64 bits overflow.
+    ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
-    ///
-    /// let ulid = Ulid::from(tuple);
-    ///
-    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let ulid = Ulid::from(0x0000_0000_0000_F00F_0000_0000_0000_0000);
     ///
-    /// assert_eq!(ulid, expected_ulid);
+    /// assert_eq!(ulid.fingerprint(), 0xF00F);
     /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u16 {
+        u16::from_be_bytes([self.bytes[6], self.bytes[7]])
+    }
+
+    /// Returns a new ULID with the [`fingerprint`](Self::fingerprint) replaced by `fingerprint`.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
-    ///
-    /// let ulid : Ulid = tuple.into();
-    ///
-    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let ulid = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
+    /// let fingerprinted = ulid.with_fingerprint(0xF00F);
     ///
-    /// assert_eq!(ulid, expected_ulid);
+    /// assert_eq!(fingerprinted, Ulid::from(0x0000_0000_0001_F00F_FFFF_FFFF_FFFF_FFFF));
     /// ```
     #[must_use]
-    fn from(value: (u64, u64)) -> Self {
-        Self { value }
+    pub fn with_fingerprint(mut self, fingerprint: u16) -> Self {
+        self.bytes[6..8].copy_from_slice(&fingerprint.to_be_bytes());
+        self
     }
-}
 
-impl From<Ulid> for (u64, u64) {
+    /// Returns the node ID stored in the `node_bits` most significant bits of
+    /// the random part, see [`NodeUlidGenerator`](crate::NodeUlidGenerator).
+    ///
+    /// A node ID of 16 bits is the same as the [`fingerprint`](Self::fingerprint).
+    ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let ulid = Ulid::from(0x0000_0000_0000_F00F_0000_0000_0000_0000);
     ///
-    /// let tuple = <(u64, u64)>::from(ulid);
+    /// assert_eq!(ulid.node_id(4), 0xF);
+    /// assert_eq!(ulid.node_id(16), 0xF00F);
+    /// assert_eq!(ulid.node_id(20), 0xF00F0);
+    /// ```
     ///
-    /// let expected_tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
+    /// # Panics
     ///
-    /// assert_eq!(tuple, expected_tuple);
-    /// ```
+    /// Panics if `node_bits` is larger than 32.
+    #[must_use]
+    pub fn node_id(&self, node_bits: u32) -> u32 {
+        assert!(node_bits <= 32, "node ID can't have more than 32 bits");
+
+        ((self.to_u128() >> (80 - node_bits)) & ((1 << node_bits) - 1)) as u32
+    }
+
+    /// Returns a new ULID with the [`node_id`](Self::node_id) stored in the
+    /// `node_bits` most significant bits of the random part replaced by `node_id`.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let ulid = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
     ///
-    /// let tuple : (u64, u64) = ulid.into();
+    /// assert_eq!(
+    ///     ulid.with_node_id(12, 0x2A5),
+    ///     Ulid::from(0x0000_0000_0001_2A5F_FFFF_FFFF_FFFF_FFFF)
+    /// );
+    /// ```
     ///
-    /// let expected_tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
+    /// # Panics
     ///
-    /// assert_eq!(tuple, expected_tuple);
-    /// ```
+    /// Panics if `node_bits` is larger than 32 or if `node_id` doesn't fit
+    /// into `node_bits` bits.
     #[must_use]
-    fn from(ulid: Ulid) -> Self {
-        ulid.value
+    pub fn with_node_id(self, node_bits: u32, node_id: u32) -> Self {
+        assert!(node_bits <= 32, "node ID can't have more than 32 bits");
+        assert!(
+            u64::from(node_id) >> node_bits == 0,
+            "node ID {node_id} doesn't fit into {node_bits} bits"
+        );
+
+        let shift = 80 - node_bits;
+        let mask = ((1 << node_bits) - 1) << shift;
+        Self::from_u128((self.to_u128() & !mask) | (u128::from(node_id) << shift))
     }
-}
 
-impl From<u128> for Ulid {
+    /// Returns a reference to the 16 bytes of this ULID in big-endian byte order.
+    ///
+    /// Unlike `<[u8; 16]>::from(ulid)` this does not copy the bytes, which is
+    /// useful for byte-oriented APIs like key-value stores or hashers.
+    ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
     ///
-    /// let ulid = Ulid::from(value);
+    /// assert_eq!(
+    ///     ulid.as_bytes(),
+    ///     &[0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F]
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+
+    /// Returns the memory representation of this ULID as a byte array in
+    /// big-endian byte order.
     ///
-    /// let expected_ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    /// This is the same as `<[u8; 16]>::from(ulid)` and preserves the sort order.
     ///
-    /// assert_eq!(ulid, expected_ulid);
-    /// ```
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
-    ///
-    /// let ulid : Ulid = value.into();
-    ///
-    /// let expected_ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
     ///
-    /// assert_eq!(ulid, expected_ulid);
+    /// assert_eq!(
+    ///     ulid.to_be_bytes(),
+    ///     [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F]
+    /// );
     /// ```
     #[must_use]
-    fn from(value: u128) -> Self {
-        let value = ((value >> 64) as u64, (value & 0xFFFF_FFFF_FFFF_FFFF) as u64);
-        Self { value }
+    pub const fn to_be_bytes(&self) -> [u8; 16] {
+        self.bytes
     }
-}
 
-impl From<Ulid> for u128 {
+    /// Returns the memory representation of this ULID as a byte array in
+    /// little-endian byte order.
+    ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
     ///
-    /// let value = <u128>::from(ulid);
+    /// assert_eq!(
+    ///     ulid.to_le_bytes(),
+    ///     [0x0F, 0xF0, 0xEE, 0xDD, 0xCC, 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn to_le_bytes(&self) -> [u8; 16] {
+        self.to_u128().to_le_bytes()
+    }
+
+    /// Creates a ULID from its memory representation as a byte array in
+    /// big-endian byte order.
     ///
-    /// let expected_value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    /// This is the same as `Ulid::from(bytes)`.
     ///
-    /// assert_eq!(value, expected_value);
-    /// ```
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
     ///
-    /// let ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    /// let ulid = Ulid::from_be_bytes([
+    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
+    /// ]);
     ///
-    /// let value : u128 = ulid.into();
+    /// assert_eq!(ulid, Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F));
+    /// ```
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        Self { bytes }
+    }
+
+    /// Creates a ULID from its memory representation as a byte array in
+    /// little-endian byte order.
     ///
-    /// let expected_value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    /// # Examples
     ///
-    /// assert_eq!(value, expected_value);
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_le_bytes([
+    ///     0x0F, 0xF0, 0xEE, 0xDD, 0xCC, 0xBB, 0xAA, 0x99, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11,
+    /// ]);
+    ///
+    /// assert_eq!(ulid, Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F));
     /// ```
     #[must_use]
-    fn from(ulid: Ulid) -> Self {
-        (Self::from(ulid.value.0) << 64) | Self::from(ulid.value.1)
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Self::from_u128(u128::from_le_bytes(bytes))
     }
-}
 
-impl TryFrom<&[u8]> for Ulid {
-    type Error = DecodingError;
-
-    /// Returns a ULID for the given slice of bytes or `DecodingError::InvalidLength`
-    /// if the slice does not contain exactly 16 bytes.
+    /// Returns the big-endian bytes of this ULID.
+    ///
+    /// This is the same as [`to_be_bytes`](Self::to_be_bytes) and
+    /// `<[u8; 16]>::from(ulid)`, without consuming the ULID.
     ///
     /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
-    /// use std::convert::TryFrom;
-    /// use std::convert::TryInto;
     ///
-    /// let bytes: [u8; 18] = [
-    ///     0x00,
-    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
-    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
-    ///     0x00,
-    /// ];
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
     ///
-    /// let ulid : Ulid = Ulid::try_from(&bytes[1..17])?;
+    /// assert_eq!(ulid.to_bytes(), <[u8; 16]>::from(ulid));
+    /// ```
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.bytes
+    }
+
+    /// Returns the value of this ULID as `u128`.
     ///
-    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// This is the same as `u128::from(ulid)`, but reads better in method chains.
     ///
-    /// assert_eq!(ulid, expected_ulid);
+    /// # Examples
     ///
-    /// let ulid : Ulid = (&bytes[1..17]).try_into()?;
+    /// ```
+    /// use rusty_ulid::Ulid;
     ///
-    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    /// let ulids = [Ulid::from(2), Ulid::from(40)];
     ///
-    /// assert_eq!(ulid, expected_ulid);
-    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// assert_eq!(ulids.iter().map(Ulid::as_u128).sum::<u128>(), 42);
     /// ```
+    #[must_use]
+    pub const fn as_u128(&self) -> u128 {
+        self.to_u128()
+    }
+
+    /// Returns the value of this ULID as pair of its most and least significant 64 bits.
+    ///
+    /// This is the same as `<(u64, u64)>::from(ulid)`.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
-    /// use rusty_ulid::DecodingError;
-    /// use std::convert::TryFrom;
     ///
-    /// let mut bytes: [u8; 17] = [0; 17];
-    /// let result = Ulid::try_from(&bytes[0..]);
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
     ///
-    /// assert_eq!(result, Err(DecodingError::InvalidLength))
+    /// assert_eq!(
+    ///     ulid.as_u64_pair(),
+    ///     (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F)
+    /// );
     /// ```
+    #[must_use]
+    pub const fn as_u64_pair(&self) -> (u64, u64) {
+        self.to_u64_tuple()
+    }
+
+    /// Returns the most and least significant 64 bits of this ULID as signed
+    /// integers, e.g. for two `BIGINT` columns or a pair of Java `long`s.
+    ///
+    /// The most significant bit of both halves is flipped, so sorting by the
+    /// first and then by the second value preserves the order of ULIDs. The
+    /// smallest ULID maps to `(i64::MIN, i64::MIN)`. Use
+    /// [`from_i64_pair`](Self::from_i64_pair) to convert back.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rusty_ulid::Ulid;
-    /// use rusty_ulid::DecodingError;
-    /// use std::convert::TryFrom;
     ///
-    /// let mut bytes: [u8; 15] = [0; 15];
-    /// let result = Ulid::try_from(&bytes[0..]);
+    /// assert_eq!(Ulid::from(0).to_i64_pair(), (i64::MIN, i64::MIN));
+    /// assert_eq!(Ulid::from(u128::MAX).to_i64_pair(), (i64::MAX, i64::MAX));
     ///
-    /// assert_eq!(result, Err(DecodingError::InvalidLength))
+    /// let first = Ulid::from(0x0162_A27A_F618_6CAA_7FFF_FFFF_FFFF_FFFF);
+    /// let second = Ulid::from(0x0162_A27A_F618_6CAA_8000_0000_0000_0000);
+    /// assert!(first.to_i64_pair() < second.to_i64_pair());
     /// ```
-    fn try_from(bytes: &[u8]) -> Result<Self, DecodingError> {
-        if bytes.len() != 16 {
-            return Err(DecodingError::InvalidLength);
-        }
-
-        #[rustfmt::skip]
-        let high = (u64::from(bytes[0]) << 56)
-            | (u64::from(bytes[1]) << 48)
-            | (u64::from(bytes[2]) << 40)
-            | (u64::from(bytes[3]) << 32)
-            | (u64::from(bytes[4]) << 24)
-            | (u64::from(bytes[5]) << 16)
-            | (u64::from(bytes[6]) << 8)
-            | u64::from(bytes[7]);
-
-        #[rustfmt::skip]
-        let low = (u64::from(bytes[8]) << 56)
-            | (u64::from(bytes[9]) << 48)
-            | (u64::from(bytes[10]) << 40)
-            | (u64::from(bytes[11]) << 32)
-            | (u64::from(bytes[12]) << 24)
-            | (u64::from(bytes[13]) << 16)
-            | (u64::from(bytes[14]) << 8)
-            | u64::from(bytes[15]);
-
-        let value = (high, low);
-        Ok(Self { value })
+    #[must_use]
+    pub const fn to_i64_pair(&self) -> (i64, i64) {
+        let (high, low) = self.to_u64_tuple();
+        ((high ^ (1 << 63)) as i64, (low ^ (1 << 63)) as i64)
     }
-}
 
-#[cfg(feature = "serde")]
-impl Serialize for Ulid {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        if serializer.is_human_readable() {
-            serializer.serialize_str(&self.to_string())
-        } else {
-            let bytes: [u8; 16] = (*self).into();
-            serializer.serialize_bytes(&bytes)
-        }
+    /// Creates a ULID from a pair of signed integers created by
+    /// [`to_i64_pair`](Self::to_i64_pair).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(Ulid::from_i64_pair(ulid.to_i64_pair()), ulid);
+    /// ```
+    #[must_use]
+    pub const fn from_i64_pair(value: (i64, i64)) -> Self {
+        Self::from_u64_tuple((value.0 as u64 ^ (1 << 63), value.1 as u64 ^ (1 << 63)))
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for Ulid {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        if deserializer.is_human_readable() {
-            struct UlidStringVisitor;
+    /// Returns the value of this ULID as a signed integer, e.g. for a signed
+    /// 128-bit integer column.
+    ///
+    /// The most significant bit is flipped, so the order of ULIDs is preserved.
+    /// The smallest ULID maps to `i128::MIN`, the largest to `i128::MAX`. Use
+    /// [`from_i128`](Self::from_i128) to convert back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// assert_eq!(Ulid::from(0).to_i128(), i128::MIN);
+    /// assert_eq!(Ulid::from(u128::MAX).to_i128(), i128::MAX);
+    ///
+    /// let first = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    /// let second = Ulid::from(0x8162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    /// assert!(first.to_i128() < second.to_i128());
+    /// ```
+    #[must_use]
+    pub const fn to_i128(&self) -> i128 {
+        (self.to_u128() ^ (1 << 127)) as i128
+    }
 
-            impl de::Visitor<'_> for UlidStringVisitor {
-                type Value = Ulid;
+    /// Creates a ULID from a signed integer created by [`to_i128`](Self::to_i128).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(Ulid::from_i128(ulid.to_i128()), ulid);
+    /// assert_eq!(Ulid::from_i128(i128::MIN), Ulid::from(0));
+    /// ```
+    #[must_use]
+    pub const fn from_i128(value: i128) -> Self {
+        Self::from_u128(value as u128 ^ (1 << 127))
+    }
 
-                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    write!(formatter, "a ULID string")
-                }
+    // big-endian bytes and u128 convert without any shifting on big-endian
+    // machines and with a single byte swap on little-endian ones
+    const fn to_u128(self) -> u128 {
+        u128::from_be_bytes(self.bytes)
+    }
 
-                fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
-                    value.parse::<Ulid>().map_err(E::custom)
-                }
-            }
+    const fn from_u128(value: u128) -> Self {
+        Self {
+            bytes: value.to_be_bytes(),
+        }
+    }
 
-            deserializer.deserialize_str(UlidStringVisitor)
-        } else {
-            struct UlidBytesVisitor;
+    const fn to_u64_tuple(self) -> (u64, u64) {
+        let value = self.to_u128();
+        ((value >> 64) as u64, value as u64)
+    }
 
-            impl de::Visitor<'_> for UlidBytesVisitor {
-                type Value = Ulid;
+    const fn from_u64_tuple(value: (u64, u64)) -> Self {
+        Self::from_u128(((value.0 as u128) << 64) | value.1 as u128)
+    }
 
-                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    write!(formatter, "16 ULID bytes")
-                }
+    /// Returns the bytes of a [UUIDv7][uuidv7] sharing the timestamp and randomness of this ULID.
+    ///
+    /// UUIDv7 uses the same 48-bit millisecond timestamp prefix as ULID. The
+    /// lowest six bits of the random part are dropped and the remaining 74 bits
+    /// are shifted around the version and variant bits of the UUID, so the sort
+    /// order is preserved: if `a <= b`, then `a.to_uuid_v7() <= b.to_uuid_v7()`.
+    ///
+    /// [uuidv7]: https://www.rfc-editor.org/rfc/rfc9562#name-uuid-version-7
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0189_4B1E_1A2B_FFFF_FFFF_FFFF_FFFF_FFFF);
+    /// let uuid = ulid.to_uuid_v7();
+    ///
+    /// assert_eq!(u128::from_be_bytes(uuid), 0x0189_4B1E_1A2B_7FFF_BFFF_FFFF_FFFF_FFFF);
+    /// ```
+    #[must_use]
+    pub fn to_uuid_v7(&self) -> [u8; 16] {
+        let value = self.to_u128();
+        let random = (value & RANDOM_PART_MASK) >> UUID_V7_DROPPED_BITS;
+        let value = (value & TIMESTAMP_PART_MASK)
+            | UUID_V7_VERSION_VARIANT
+            | ((random << 2) & UUID_V7_RAND_A_MASK)
+            | (random & UUID_V7_RAND_B_MASK);
+
+        value.to_be_bytes()
+    }
 
-                fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Ulid, E> {
-                    Ulid::try_from(value).map_err(E::custom)
-                }
-            }
-            deserializer.deserialize_bytes(UlidBytesVisitor)
+    /// Creates a ULID from the bytes of a [UUIDv7][uuidv7].
+    ///
+    /// Returns `None` if `uuid` does not carry the version 7 and RFC variant bits.
+    ///
+    /// This is the inverse of [`to_uuid_v7`](Self::to_uuid_v7). The lowest six
+    /// bits of the random part, which a UUIDv7 can't hold, are zero.
+    ///
+    /// [uuidv7]: https://www.rfc-editor.org/rfc/rfc9562#name-uuid-version-7
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let uuid = 0x0189_4B1E_1A2B_7123_8456_789A_BCDE_F012_u128.to_be_bytes();
+    /// let ulid = Ulid::from_uuid_v7(uuid).unwrap();
+    ///
+    /// assert_eq!(ulid.timestamp(), 0x0189_4B1E_1A2B);
+    /// assert_eq!(ulid.to_uuid_v7(), uuid);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// // version 4
+    /// let uuid = 0x0189_4B1E_1A2B_4123_8456_789A_BCDE_F012_u128.to_be_bytes();
+    ///
+    /// assert_eq!(Ulid::from_uuid_v7(uuid), None);
+    /// ```
+    #[must_use]
+    pub fn from_uuid_v7(uuid: [u8; 16]) -> Option<Self> {
+        let value = u128::from_be_bytes(uuid);
+        if value & UUID_VERSION_VARIANT_MASK != UUID_V7_VERSION_VARIANT {
+            return None;
         }
-    }
-}
 
-#[cfg(all(doctest, feature = "rand", feature = "chrono"))]
-mod doc_tests {
-    use doc_comment::doctest;
-    doctest!("../README.md", readme);
-}
+        let random = ((value & UUID_V7_RAND_A_MASK) >> 2) | (value & UUID_V7_RAND_B_MASK);
 
-#[cfg(all(test, feature = "serde"))]
-mod serde_tests {
-    use super::*;
-    use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Readable, Token};
+        Some(Self::from_u128(
+            (value & TIMESTAMP_PART_MASK) | (random << UUID_V7_DROPPED_BITS),
+        ))
+    }
 
-    #[test]
-    fn test_serde_readable() {
-        use serde_test::Configure;
+    /// Returns the string representaton of this ULID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0);
+    ///
+    /// assert_eq!(ulid.to_string(), "00000000000000000000000000");
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF);
+    ///
+    /// assert_eq!(ulid.to_string(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    /// ```
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    // impl fmt::Display is using this method
+    // https://github.com/rust-lang/rust-clippy/issues/4396
+    #[allow(clippy::wrong_self_convention)]
+    // 1.53.0 still requires clippy::wrong_self_convention
+    // 1.54.0-beta fixes this false positive
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_string(&self) -> String {
+        let mut string = String::with_capacity(26);
 
-        let ulid = Ulid::from_str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap();
-        assert_tokens(
-            &ulid.readable(),
-            &[Token::Str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ")],
-        );
+        self.append_to(&mut string);
 
-        let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
-        assert_tokens(
-            &ulid.readable(),
-            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")],
-        );
+        string
     }
 
-    #[test]
-    fn test_serde_compact() {
-        use serde_test::Configure;
+    /// Appends the canonical string representation of this ULID to `string`.
+    ///
+    /// This allows building a string of many ULIDs, e.g. a CSV line or an SQL
+    /// `IN` list, with a single allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulids = [Ulid::from(0), Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67)];
+    ///
+    /// let mut line = String::with_capacity(ulids.len() * 27);
+    /// for ulid in &ulids {
+    ///     if !line.is_empty() {
+    ///         line.push(',');
+    ///     }
+    ///     ulid.append_to(&mut line);
+    /// }
+    ///
+    /// assert_eq!(line, "00000000000000000000000000,01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn append_to(&self, string: &mut String) {
+        crockford::append_crockford_u64_tuple(self.to_u64_tuple(), string);
+    }
+
+    /// Wraps this ULID in [`Redacted`], hiding its random part when formatted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(format!("token={}", ulid.redacted()), "token=01CAH7NXGR…***");
+    /// ```
+    #[must_use]
+    pub const fn redacted(self) -> Redacted {
+        Redacted(self)
+    }
+
+    /// Returns the 26 ASCII bytes of the canonical string representation of
+    /// this ULID, without allocating a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(&ulid.to_ascii_bytes(), b"01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// assert_eq!(Ulid::from_ascii_bytes(&ulid.to_ascii_bytes()), Ok(ulid));
+    /// ```
+    #[must_use]
+    pub fn to_ascii_bytes(&self) -> [u8; 26] {
+        let mut buffer = [0; 26];
+        crockford::encode_crockford_u128_into(self.to_u128(), &mut buffer);
+        buffer
+    }
+
+    /// Writes the ASCII bytes of this ULID into `buffer` and returns them as the
+    /// canonical string representation.
+    pub(crate) fn encode_to_str<'a>(&self, buffer: &'a mut [u8; 26]) -> &'a str {
+        crockford::encode_crockford_u128_into(self.to_u128(), buffer);
+        crockford::ascii_str(buffer)
+    }
+
+    /// Writes the canonical string representation of this ULID to `writer`
+    /// without allocating a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// let mut buffer = Vec::new();
+    /// ulid.write_to(&mut buffer)?;
+    ///
+    /// assert_eq!(buffer, b"01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of writing to `writer`.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.to_ascii_bytes())
+    }
+
+    /// Writes the canonical string representation of this ULID to the
+    /// `fmt::Write` `writer` without allocating a `String`.
+    ///
+    /// Unlike the `Display` implementation, this ignores any formatting flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use std::fmt::Write;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// let mut line = String::from("id=");
+    /// ulid.fmt_to(&mut line)?;
+    /// line.write_str(";")?;
+    ///
+    /// assert_eq!(line, "id=01CAH7NXGRDJNE9B1NY7PQGYV7;");
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of writing to `writer`.
+    pub fn fmt_to<W: fmt::Write + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_str(self.encode_to_str(&mut [0; 26]))
+    }
+
+    /// Parses the 26 ASCII bytes of a ULID string without UTF-8 validation.
+    ///
+    /// `FromStr` uses the same byte-oriented decoding after checking that the
+    /// string is ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_ascii_bytes(b"01CAH7NXGRDJNE9B1NY7PQGYV7")?;
+    ///
+    /// assert_eq!(ulid, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidChar` containing `char::from(byte)` for a byte that is not
+    /// part of the [crockford Base32][crockford] alphabet and `DataTypeOverflow` if
+    /// the value would exceed 128 bits.
+    ///
+    /// [crockford]: https://crockford.com/wrmg/base32.html
+    pub fn from_ascii_bytes(bytes: &[u8; 26]) -> Result<Self, DecodingError> {
+        let value = crockford::parse_crockford_u64_tuple_bytes(bytes)?;
+
+        Ok(Self::from(value))
+    }
+
+    /// Parses a ULID from a slice of ASCII bytes of any length, e.g. a field of
+    /// a raw protocol buffer, without UTF-8 validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{DecodingError, Ulid};
+    ///
+    /// let line = b"id=01CAH7NXGRDJNE9B1NY7PQGYV7;";
+    ///
+    /// let ulid = Ulid::from_ascii_slice(&line[3..29])?;
+    /// assert_eq!(ulid, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+    ///
+    /// assert_eq!(
+    ///     Ulid::from_ascii_slice(&line[3..]),
+    ///     Err(DecodingError::InvalidLength {
+    ///         expected: 26,
+    ///         actual: 27
+    ///     })
+    /// );
+    /// # Ok::<(), DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLength` if `bytes` does not contain exactly 26 bytes and
+    /// the same errors as [`from_ascii_bytes`](Self::from_ascii_bytes) otherwise.
+    pub fn from_ascii_slice(bytes: &[u8]) -> Result<Self, DecodingError> {
+        let value = crockford::parse_crockford_u64_tuple_bytes(bytes)?;
+
+        Ok(Self::from(value))
+    }
+
+    /// Parses a ULID string after removing surrounding ASCII whitespace and
+    /// matching pairs of double or single quotes.
+    ///
+    /// This is meant for IDs copy-pasted from logs or CSV files. Whitespace and
+    /// quotes are removed alternately, so `" \"01CAH7NXGRDJNE9B1NY7PQGYV7\" "`
+    /// is accepted as well. Hyphens separating groups, as written by
+    /// [`to_base32_words`](Self::to_base32_words), are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::parse_lenient("\"01CAH7NXGRDJNE9B1NY7PQGYV7\"\r\n")?;
+    /// assert_eq!(ulid.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    ///
+    /// let ulid = Ulid::parse_lenient("  '01CAH7NXGRDJNE9B1NY7PQGYV7'")?;
+    /// assert_eq!(ulid.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    ///
+    /// let ulid = Ulid::parse_lenient("01CAH7-NXGRD-JNE9B-1NY7P-QGYV7")?;
+    /// assert_eq!(ulid.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`from_base32_words`](Self::from_base32_words) are returned
+    /// for the trimmed string. The position of an invalid character refers to the
+    /// untrimmed `input`.
+    pub fn parse_lenient(input: &str) -> Result<Self, DecodingError> {
+        let mut start = 0;
+        let mut trimmed = input;
+        loop {
+            let without_whitespace = trimmed.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            start += trimmed.len() - without_whitespace.len();
+            trimmed = without_whitespace.trim_end_matches(|c: char| c.is_ascii_whitespace());
+
+            let unquoted = ['"', '\''].iter().find_map(|quote| {
+                trimmed
+                    .strip_prefix(*quote)
+                    .and_then(|inner| inner.strip_suffix(*quote))
+            });
+            match unquoted {
+                Some(inner) => {
+                    start += 1;
+                    trimmed = inner;
+                }
+                None => break,
+            }
+        }
+
+        Self::from_base32_words(trimmed).map_err(|error| error.offset_position(start))
+    }
+
+    /// Returns the string representation of this ULID split into hyphen-separated
+    /// groups of `n` characters for easier human transcription.
+    ///
+    /// Groups are counted from the end of the string. Leftover characters are
+    /// added to the first group, so `n = 5` results in groups of 6-5-5-5-5 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use std::str::FromStr;
+    ///
+    /// let ulid = Ulid::from_str("01CAH7NXGRDJNE9B1NY7PQGYV7")?;
+    ///
+    /// assert_eq!(ulid.to_base32_words(5), "01CAH7-NXGRD-JNE9B-1NY7P-QGYV7");
+    /// assert_eq!(ulid.to_base32_words(13), "01CAH7NXGRDJN-E9B1NY7PQGYV7");
+    /// assert_eq!(ulid.to_base32_words(26), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or larger than 26.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_base32_words(&self, n: usize) -> String {
+        assert!(
+            (1..=26).contains(&n),
+            "ULID words must contain between 1 and 26 characters"
+        );
+
+        let string = self.to_string();
+        let first_group_length = n + 26 % n;
+        let mut result = String::with_capacity(26 + 26 / n - 1);
+        result.push_str(&string[..first_group_length]);
+        for index in (first_group_length..26).step_by(n) {
+            result.push('-');
+            result.push_str(&string[index..index + n]);
+        }
+
+        result
+    }
+
+    /// Parses a ULID string that may be split into hyphen-separated groups,
+    /// e.g. as returned by [`to_base32_words`](Self::to_base32_words).
+    ///
+    /// All hyphens are ignored, regardless of their position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use std::str::FromStr;
+    ///
+    /// let ulid = Ulid::from_base32_words("01CAH7-NXGRD-JNE9B-1NY7P-QGYV7")?;
+    ///
+    /// assert_eq!(ulid, Ulid::from_str("01CAH7NXGRDJNE9B1NY7PQGYV7")?);
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Ulid::from_str`] for the string without hyphens.
+    /// Hyphens are removed before the length check, so the `actual` length of
+    /// [`DecodingError::InvalidLength`] doesn't count them, while the `position`
+    /// of [`DecodingError::InvalidChar`] refers to `s` including its hyphens.
+    ///
+    /// ```
+    /// use rusty_ulid::{DecodingError, Ulid};
+    ///
+    /// assert_eq!(
+    ///     Ulid::from_base32_words("01CAH7-NXGRD-JNE9B-1NY7P-QGYV"),
+    ///     Err(DecodingError::InvalidLength {
+    ///         expected: 26,
+    ///         actual: 25
+    ///     })
+    /// );
+    /// ```
+    pub fn from_base32_words(s: &str) -> Result<Self, DecodingError> {
+        let hyphens = s.bytes().filter(|&byte| byte == b'-').count();
+        if hyphens == 0 {
+            return s.parse();
+        }
+
+        let length = s.len() - hyphens;
+        if length != 26 {
+            return Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: length,
+            });
+        }
+
+        // the symbols without hyphens and their byte positions in `s`
+        let mut bytes = [0; 26];
+        let mut positions = [0; 26];
+        let symbols = s.bytes().enumerate().filter(|&(_, byte)| byte != b'-');
+        for (index, (position, byte)) in symbols.enumerate() {
+            bytes[index] = byte;
+            positions[index] = position;
+        }
+
+        Self::from_ascii_bytes(&bytes).map_err(|error| match error {
+            DecodingError::InvalidChar {
+                character,
+                position,
+            } => {
+                // the first invalid byte of a non-ASCII character is its first one
+                let position = positions[position];
+                let character = s[position..].chars().next().unwrap_or(character);
+                DecodingError::InvalidChar {
+                    character,
+                    position,
+                }
+            }
+            other => other,
+        })
+    }
+
+    /// Returns the inclusive range `(min, max)` of all ULIDs whose string representation
+    /// starts with the given `prefix` of 1 to 26 characters.
+    ///
+    /// This can be used to search for a ULID in a range-scannable store if only
+    /// the first characters are known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use std::str::FromStr;
+    ///
+    /// let (min, max) = Ulid::partial_from_prefix("01CAH7NXGR")?;
+    ///
+    /// assert_eq!(min, Ulid::from_str("01CAH7NXGR0000000000000000")?);
+    /// assert_eq!(max, Ulid::from_str("01CAH7NXGRZZZZZZZZZZZZZZZZ")?);
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing an empty `prefix` or one with more than 26 bytes results in `InvalidLength`
+    /// with an `expected` maximum length of 26.
+    ///
+    /// Parsing a `prefix` starting with a character larger than `7` results in `DataTypeOverflow`.
+    ///
+    /// Parsing a `prefix` containing an invalid character results in `InvalidChar` containing
+    /// the character and its byte position.
+    pub fn partial_from_prefix(prefix: &str) -> Result<(Self, Self), DecodingError> {
+        if prefix.is_empty() || prefix.len() > 26 {
+            return Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: prefix.len(),
+            });
+        }
+
+        let mut value: u128 = 0;
+        let mut length = 0;
+        for (position, character) in prefix.char_indices() {
+            let digit = u8::try_from(character)
+                .ok()
+                .and_then(crockford::decode_digit)
+                .ok_or(DecodingError::InvalidChar {
+                    character,
+                    position,
+                })?;
+            if length == 0 && digit > 7 {
+                return Err(DecodingError::DataTypeOverflow);
+            }
+            value = (value << 5) | u128::from(digit);
+            length += 1;
+        }
+
+        let remaining_bits = 5 * (26 - length);
+        let min = value << remaining_bits;
+        let max = min | ((1 << remaining_bits) - 1);
+
+        Ok((min.into(), max.into()))
+    }
+
+    /// Returns this ULID as URN, i.e. its string representation prefixed with `ulid:`.
+    ///
+    /// This tags the identifier with its scheme, similar to `urn:uuid:` for UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(ulid.to_urn(), "ulid:01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_urn(&self) -> String {
+        let mut string = String::with_capacity(URN_PREFIX.len() + 26);
+        string.push_str(URN_PREFIX);
+        crockford::append_crockford_u64_tuple(self.to_u64_tuple(), &mut string);
+
+        string
+    }
+
+    /// Parses a ULID from a URN as returned by [`to_urn`](Self::to_urn).
+    ///
+    /// The `ulid:` prefix is matched case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_urn("ULID:01CAH7NXGRDJNE9B1NY7PQGYV7")?;
+    ///
+    /// assert_eq!(ulid, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing a string that does not start with `ulid:` results in `InvalidChar`
+    /// containing the first character that differs, or `InvalidLength` if the string
+    /// is shorter than the prefix.
+    ///
+    /// Otherwise, the same errors as [`Ulid::from_str`] are returned for the string
+    /// following the prefix. The position of an invalid character refers to `urn`.
+    pub fn from_urn(urn: &str) -> Result<Self, DecodingError> {
+        for ((position, character), expected) in urn.char_indices().zip(URN_PREFIX.chars()) {
+            if !character.eq_ignore_ascii_case(&expected) {
+                return Err(DecodingError::InvalidChar {
+                    character,
+                    position,
+                });
+            }
+        }
+        if urn.len() < URN_PREFIX.len() {
+            return Err(DecodingError::InvalidLength {
+                expected: URN_PREFIX.len() + 26,
+                actual: urn.len(),
+            });
+        }
+
+        urn[URN_PREFIX.len()..]
+            .parse()
+            .map_err(|error: DecodingError| error.offset_position(URN_PREFIX.len()))
+    }
+
+    /// Returns the 22 character, unpadded [base64url][base64url] representation of
+    /// the 16 bytes of this ULID.
+    ///
+    /// This is meant for contexts where every character counts, like QR payloads
+    /// or short URLs. Unlike the canonical representation, the base64url
+    /// representation does not sort like the ULID itself.
+    ///
+    /// [base64url]: https://www.rfc-editor.org/rfc/rfc4648#section-5
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(ulid.to_base64(), "AWKievYYbKrkrDXx7Xh7Zw");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        let value = self.to_u128();
+
+        let mut string = String::with_capacity(22);
+        for shift in (2..=122).rev().step_by(6) {
+            string.push(BASE64_ALPHABET[((value >> shift) & 0x3F) as usize] as char);
+        }
+        // the last symbol contains the two remaining bits followed by four zero bits
+        string.push(BASE64_ALPHABET[((value & 0b11) << 4) as usize] as char);
+
+        string
+    }
+
+    /// Parses the 22 character, unpadded [base64url][base64url] representation of
+    /// a ULID as returned by [`to_base64`](Self::to_base64).
+    ///
+    /// [base64url]: https://www.rfc-editor.org/rfc/rfc4648#section-5
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_base64("AWKievYYbKrkrDXx7Xh7Zw")?;
+    ///
+    /// assert_eq!(ulid, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing a string with other than 22 bytes results in `InvalidLength`.
+    ///
+    /// Parsing a string containing a character outside of the base64url alphabet,
+    /// including padding, results in `InvalidChar` containing the character and its
+    /// byte position.
+    ///
+    /// Parsing a string whose last character has any of its four unused bits set
+    /// results in `DataTypeOverflow`.
+    pub fn from_base64(input: &str) -> Result<Self, DecodingError> {
+        if input.len() != 22 {
+            return Err(DecodingError::InvalidLength {
+                expected: 22,
+                actual: input.len(),
+            });
+        }
+
+        let mut value: u128 = 0;
+        for (position, character) in input.char_indices() {
+            let digit = match character {
+                'A'..='Z' => u32::from(character) - u32::from('A'),
+                'a'..='z' => u32::from(character) - u32::from('a') + 26,
+                '0'..='9' => u32::from(character) - u32::from('0') + 52,
+                '-' => 62,
+                '_' => 63,
+                _ => {
+                    return Err(DecodingError::InvalidChar {
+                        character,
+                        position,
+                    })
+                }
+            };
+
+            if position < 21 {
+                value = (value << 6) | u128::from(digit);
+            } else if digit & 0xF != 0 {
+                return Err(DecodingError::DataTypeOverflow);
+            } else {
+                value = (value << 2) | u128::from(digit >> 4);
+            }
+        }
+
+        Ok(Self::from_u128(value))
+    }
+
+    /// Returns the 32 character, lowercase hexadecimal representation of this ULID.
+    ///
+    /// This is the same as `format!("{ulid:032x}")`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(ulid.to_hex(), "0162a27af6186caae4ac35f1ed787b67");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        format!("{:032x}", self.to_u128())
+    }
+
+    /// Parses the 32 character hexadecimal representation of a ULID.
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_hex("0162A27AF6186CAAE4AC35F1ED787B67")?;
+    ///
+    /// assert_eq!(ulid, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing a string with other than 32 bytes results in `InvalidLength`.
+    ///
+    /// Parsing a string containing a character that is not a hexadecimal digit
+    /// results in `InvalidChar` containing the character and its byte position.
+    pub fn from_hex(input: &str) -> Result<Self, DecodingError> {
+        if input.len() != 32 {
+            return Err(DecodingError::InvalidLength {
+                expected: 32,
+                actual: input.len(),
+            });
+        }
+
+        let mut value: u128 = 0;
+        for (position, character) in input.char_indices() {
+            let digit = character.to_digit(16).ok_or(DecodingError::InvalidChar {
+                character,
+                position,
+            })?;
+            value = (value << 4) | u128::from(digit);
+        }
+
+        Ok(Self::from_u128(value))
+    }
+
+    /// Returns the canonical, hyphenated UUID string of this ULID.
+    ///
+    /// The 128 bits of the ULID are used as they are, without any conversion.
+    /// See [`to_uuid_v7`](Self::to_uuid_v7) for a conversion into a valid
+    /// version 7 UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(ulid.to_uuid_string(), "0162a27a-f618-6caa-e4ac-35f1ed787b67");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_uuid_string(&self) -> String {
+        let hex = self.to_hex();
+
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    }
+
+    /// Parses a hyphenated UUID string, the inverse of
+    /// [`to_uuid_string`](Self::to_uuid_string).
+    ///
+    /// Both lowercase and uppercase hexadecimal digits are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_uuid_str("0162a27a-f618-6caa-e4ac-35f1ed787b67")?;
+    ///
+    /// assert_eq!(ulid.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing a string with other than 36 bytes results in `InvalidLength`.
+    ///
+    /// Parsing a string containing a character that is not a hexadecimal digit,
+    /// or not a hyphen at positions 8, 13, 18 and 23, results in `InvalidChar`
+    /// containing the character and its byte position.
+    pub fn from_uuid_str(input: &str) -> Result<Self, DecodingError> {
+        if input.len() != 36 {
+            return Err(DecodingError::InvalidLength {
+                expected: 36,
+                actual: input.len(),
+            });
+        }
+
+        let mut value: u128 = 0;
+        for (position, character) in input.char_indices() {
+            let invalid_char = DecodingError::InvalidChar {
+                character,
+                position,
+            };
+            if matches!(position, 8 | 13 | 18 | 23) {
+                if character != '-' {
+                    return Err(invalid_char);
+                }
+                continue;
+            }
+
+            let digit = character.to_digit(16).ok_or(invalid_char)?;
+            value = (value << 4) | u128::from(digit);
+        }
+
+        Ok(Self::from_u128(value))
+    }
+
+    /// Parses a ULID string, only accepting the canonical uppercase alphabet.
+    ///
+    /// In contrast to [`from_str`](FromStr::from_str), lowercase letters and the
+    /// aliases `i`, `l` and `o` are rejected instead of being normalized. This is
+    /// the same as [`from_str_with_mode`](Self::from_str_with_mode) with
+    /// [`ParseMode::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{DecodingError, Ulid};
+    ///
+    /// let ulid = Ulid::from_str_strict("01CAH7NXGRDJNE9B1NY7PQGYV7")?;
+    /// assert_eq!(ulid, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+    ///
+    /// assert_eq!(
+    ///     Ulid::from_str_strict("01cah7nxgrdjne9b1ny7pqgyv7"),
+    ///     Err(DecodingError::InvalidChar {
+    ///         character: 'c',
+    ///         position: 2
+    ///     })
+    /// );
+    /// # Ok::<(), DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same as [`from_str`](FromStr::from_str). Additionally, a lowercase letter
+    /// or one of the aliases `I`, `L` and `O` results in `InvalidChar` containing
+    /// the character and its byte position.
+    pub fn from_str_strict(input: &str) -> Result<Self, DecodingError> {
+        Self::from_str_with_mode(input, ParseMode::Strict)
+    }
+
+    /// Parses a ULID string, accepting lowercase letters, aliases and hyphens
+    /// separating groups depending on `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{ParseMode, Ulid};
+    ///
+    /// let input = "01CAH7NXGRDJNE9B1NY7PQGYVl";
+    ///
+    /// assert!(Ulid::from_str_with_mode(input, ParseMode::Lenient).is_ok());
+    /// assert!(Ulid::from_str_with_mode(input, ParseMode::Strict).is_err());
+    ///
+    /// let input = "01CAH7-NXGRD-JNE9B-1NY7P-QGYV7";
+    ///
+    /// assert!(Ulid::from_str_with_mode(input, ParseMode::Lenient).is_ok());
+    /// assert!(Ulid::from_str_with_mode(input, ParseMode::Strict).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// For [`ParseMode::Lenient`] the same as [`from_base32_words`](Self::from_base32_words),
+    /// and for [`ParseMode::Strict`] the same as [`from_str_strict`](Self::from_str_strict).
+    pub fn from_str_with_mode(input: &str, mode: ParseMode) -> Result<Self, DecodingError> {
+        if mode == ParseMode::Lenient {
+            return Self::from_base32_words(input);
+        }
+
+        let ulid = Self::from_str(input)?;
+        if let Some(position) = non_canonical_position(input) {
+            return Err(DecodingError::InvalidChar {
+                character: char::from(input.as_bytes()[position]),
+                position,
+            });
+        }
+
+        Ok(ulid)
+    }
+
+    /// Returns whether `input` is a ULID string in canonical form, i.e. consisting
+    /// of 26 symbols of the canonical uppercase alphabet.
+    ///
+    /// Use [`from_str_strict`](Self::from_str_strict) to get the reason why a
+    /// string is not canonical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// assert!(Ulid::is_canonical("01CAH7NXGRDJNE9B1NY7PQGYV7"));
+    /// assert!(!Ulid::is_canonical("01cah7nxgrdjne9b1ny7pqgyv7"));
+    /// assert!(!Ulid::is_canonical("01CAH7NXGRDJNE9B1NY7PQGYVU"));
+    /// ```
+    #[must_use]
+    pub fn is_canonical(input: &str) -> bool {
+        Self::from_str_strict(input).is_ok()
+    }
+
+    /// Returns the canonical form of the ULID string `input`, borrowing `input`
+    /// if it's already canonical.
+    ///
+    /// Lowercase letters are converted to uppercase, the aliases `i` and `l` to
+    /// `1` and `o` to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use std::borrow::Cow;
+    ///
+    /// assert_eq!(
+    ///     Ulid::canonicalize("01cah7nxgrdjne9b1ny7pqgyvl")?,
+    ///     "01CAH7NXGRDJNE9B1NY7PQGYV1"
+    /// );
+    /// assert!(matches!(
+    ///     Ulid::canonicalize("01CAH7NXGRDJNE9B1NY7PQGYV7")?,
+    ///     Cow::Borrowed(_)
+    /// ));
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same as [`from_str`](FromStr::from_str), i.e. if `input` can't be
+    /// canonicalized because it isn't a ULID at all.
+    #[cfg(feature = "std")]
+    pub fn canonicalize(input: &str) -> Result<Cow<'_, str>, DecodingError> {
+        let ulid = Self::from_str(input)?;
+        match non_canonical_position(input) {
+            None => Ok(Cow::Borrowed(input)),
+            Some(_) => Ok(Cow::Owned(ulid.to_string())),
+        }
+    }
+
+    /// Returns this ULID as JSON string, i.e. its string representation in double quotes.
+    ///
+    /// This is only available when the `json` feature is enabled and does not require `serde`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0);
+    ///
+    /// assert_eq!(ulid.to_json_string(), "\"00000000000000000000000000\"");
+    /// ```
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn to_json_string(&self) -> String {
+        let mut string = String::with_capacity(28);
+
+        string.push('"');
+        crockford::append_crockford_u64_tuple(self.to_u64_tuple(), &mut string);
+        string.push('"');
+
+        string
+    }
+
+    /// Parses a ULID from a JSON string fragment, i.e. its string representation in
+    /// double quotes. Surrounding JSON whitespace is ignored.
+    ///
+    /// This is only available when the `json` feature is enabled and does not require `serde`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from_json_fragment(" \"01CAH7NXGRDJNE9B1NY7PQGYV7\"\n")?;
+    ///
+    /// assert_eq!(ulid.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing a fragment that is not enclosed in double quotes results in `InvalidChar`
+    /// containing the first or last character, or `InvalidLength` if the fragment is too short.
+    ///
+    /// Otherwise, the same errors as [`Ulid::from_str`] are returned for the quoted string.
+    /// Escape sequences are not supported since a ULID never requires them.
+    #[cfg(feature = "json")]
+    pub fn from_json_fragment(fragment: &str) -> Result<Self, DecodingError> {
+        let is_whitespace = |c| matches!(c, ' ' | '\t' | '\n' | '\r');
+        let start = fragment.len() - fragment.trim_start_matches(is_whitespace).len();
+        let fragment = fragment.trim_matches(is_whitespace);
+
+        let mut chars = fragment.chars();
+        match (chars.next(), chars.next_back()) {
+            (Some('"'), Some('"')) => chars
+                .as_str()
+                .parse()
+                .map_err(|error: DecodingError| error.offset_position(start + 1)),
+            (Some('"'), None) | (None, _) => Err(DecodingError::InvalidLength {
+                expected: 28,
+                actual: fragment.len(),
+            }),
+            (Some('"'), Some(character)) => Err(DecodingError::InvalidChar {
+                character,
+                position: start + fragment.len() - character.len_utf8(),
+            }),
+            (Some(character), _) => Err(DecodingError::InvalidChar {
+                character,
+                position: start,
+            }),
+        }
+    }
+
+    /// Parses the given ULID string in a `const` context.
+    ///
+    /// This is meant for embedding well-known ULIDs as constants. Unlike
+    /// [`Ulid::from_str`], only ASCII input is considered and errors are reported
+    /// as [`ParseConstError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// const ULID: Result<Ulid, rusty_ulid::ParseConstError> =
+    ///     Ulid::try_parse_const("01CAH7NXGRDJNE9B1NY7PQGYV7");
+    ///
+    /// assert_eq!(ULID.unwrap().timestamp(), 1523144390168);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Parsing a string with other than 26 bytes results in `InvalidLength`
+    /// containing the length.
+    ///
+    /// Parsing a string containing an invalid character results in `InvalidChar`
+    /// containing the byte position of the character.
+    ///
+    /// Parsing 26 bytes results in `DataTypeOverflow` if the ULID would overflow.
+    pub const fn try_parse_const(input: &str) -> Result<Self, ParseConstError> {
+        let bytes = input.as_bytes();
+        if bytes.len() != 26 {
+            return Err(ParseConstError::InvalidLength(bytes.len()));
+        }
+
+        let mut value: u128 = 0;
+        let mut position = 0;
+        while position < 26 {
+            let digit = match crockford::decode_digit(bytes[position]) {
+                Some(digit) => digit,
+                None => return Err(ParseConstError::InvalidChar(position)),
+            };
+            if position == 0 && digit > 7 {
+                return Err(ParseConstError::DataTypeOverflow);
+            }
+            value = (value << 5) | digit as u128;
+            position += 1;
+        }
+
+        Ok(Self::from_u128(value))
+    }
+
+    /// Parses the given ULID string in a `const` context, failing compilation
+    /// if the string is not a valid ULID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// const ULID: Ulid = Ulid::parse_const("01CAH7NXGRDJNE9B1NY7PQGYV7");
+    ///
+    /// assert_eq!(ULID.timestamp(), 1523144390168);
+    /// ```
+    ///
+    /// The compiler error points at the offending character:
+    ///
+    /// ```compile_fail
+    /// use rusty_ulid::Ulid;
+    ///
+    /// // error: invalid ULID: invalid character at position 25
+    /// const ULID: Ulid = Ulid::parse_const("01CAH7NXGRDJNE9B1NY7PQGYVU");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [`message`](ParseConstError::message) of the [`ParseConstError`]
+    /// if the string is not a valid ULID.
+    #[must_use]
+    pub const fn parse_const(input: &str) -> Self {
+        match Self::try_parse_const(input) {
+            Ok(ulid) => ulid,
+            Err(error) => panic!("{}", error.message()),
+        }
+    }
+}
+
+/// Error that can occur while parsing a ULID in a `const` context.
+///
+/// See [`Ulid::try_parse_const`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseConstError {
+    /// The string does not contain exactly 26 bytes. Contains the actual length.
+    InvalidLength(usize),
+
+    /// The string contains a byte that is not allowed in a
+    /// [crockford Base32][crockford] string. Contains the position of that byte.
+    ///
+    /// [crockford]: https://crockford.com/wrmg/base32.html
+    InvalidChar(usize),
+
+    /// Parsing the string overflowed the 128 bits of a ULID.
+    DataTypeOverflow,
+}
+
+#[rustfmt::skip]
+const INVALID_CHAR_MESSAGES: [&str; 26] = [
+    "invalid ULID: invalid character at position 0",
+    "invalid ULID: invalid character at position 1",
+    "invalid ULID: invalid character at position 2",
+    "invalid ULID: invalid character at position 3",
+    "invalid ULID: invalid character at position 4",
+    "invalid ULID: invalid character at position 5",
+    "invalid ULID: invalid character at position 6",
+    "invalid ULID: invalid character at position 7",
+    "invalid ULID: invalid character at position 8",
+    "invalid ULID: invalid character at position 9",
+    "invalid ULID: invalid character at position 10",
+    "invalid ULID: invalid character at position 11",
+    "invalid ULID: invalid character at position 12",
+    "invalid ULID: invalid character at position 13",
+    "invalid ULID: invalid character at position 14",
+    "invalid ULID: invalid character at position 15",
+    "invalid ULID: invalid character at position 16",
+    "invalid ULID: invalid character at position 17",
+    "invalid ULID: invalid character at position 18",
+    "invalid ULID: invalid character at position 19",
+    "invalid ULID: invalid character at position 20",
+    "invalid ULID: invalid character at position 21",
+    "invalid ULID: invalid character at position 22",
+    "invalid ULID: invalid character at position 23",
+    "invalid ULID: invalid character at position 24",
+    "invalid ULID: invalid character at position 25",
+];
+
+impl ParseConstError {
+    /// Returns a static description of this error, usable for panics in a `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::ParseConstError;
+    ///
+    /// assert_eq!(
+    ///     ParseConstError::InvalidChar(3).message(),
+    ///     "invalid ULID: invalid character at position 3"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match *self {
+            Self::InvalidLength(_) => "invalid ULID: length must be exactly 26 bytes",
+            Self::InvalidChar(position) if position < INVALID_CHAR_MESSAGES.len() => {
+                INVALID_CHAR_MESSAGES[position]
+            }
+            Self::InvalidChar(_) => "invalid ULID: invalid character",
+            Self::DataTypeOverflow => "invalid ULID: data type overflow",
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseConstError {}
+
+impl fmt::Display for ParseConstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match *self {
+            Self::InvalidLength(length) => write!(f, "invalid length {length}, expected 26"),
+            Self::InvalidChar(position) => write!(f, "invalid character at position {position}"),
+            Self::DataTypeOverflow => write!(f, "data type overflow"),
+        }
+    }
+}
+
+/// How strictly ULID strings are parsed, see [`Ulid::from_str_with_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Accepts lowercase letters and treats `i` and `l` as `1` and `o` as `0`,
+    /// like [`from_str`](FromStr::from_str). Hyphens separating groups are
+    /// ignored, like in [`Ulid::from_base32_words`].
+    #[default]
+    Lenient,
+
+    /// Only accepts the canonical uppercase [crockford Base32][crockford] alphabet.
+    ///
+    /// [crockford]: https://crockford.com/wrmg/base32.html
+    Strict,
+}
+
+// compares the big-endian bytes as a single u128 instead of byte by byte
+impl Ord for Ulid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_u128().cmp(&other.to_u128())
+    }
+}
+
+impl PartialOrd for Ulid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str(self.encode_to_str(&mut [0; 26]))
+    }
+}
+
+/// Formats the ULID as hexadecimal number, supporting the same flags as `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+///
+/// assert_eq!(format!("{ulid:x}"), "162a27af6186caae4ac35f1ed787b67");
+/// assert_eq!(format!("{ulid:#034x}"), "0x0162a27af6186caae4ac35f1ed787b67");
+/// ```
+impl fmt::LowerHex for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.to_u128(), f)
+    }
+}
+
+/// Formats the ULID as hexadecimal number, supporting the same flags as `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+///
+/// assert_eq!(format!("{ulid:032X}"), "0162A27AF6186CAAE4AC35F1ED787B67");
+/// ```
+impl fmt::UpperHex for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.to_u128(), f)
+    }
+}
+
+impl PartialEq<u128> for Ulid {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// assert_eq!(Ulid::from(42), 42);
+    /// ```
+    fn eq(&self, other: &u128) -> bool {
+        self.to_u128() == *other
+    }
+}
+
+impl PartialEq<Ulid> for u128 {
+    fn eq(&self, other: &Ulid) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<[u8; 16]> for Ulid {
+    /// Compares with big-endian bytes, see `<[u8; 16]>::from(ulid)`.
+    fn eq(&self, other: &[u8; 16]) -> bool {
+        self.bytes == *other
+    }
+}
+
+impl PartialEq<Ulid> for [u8; 16] {
+    fn eq(&self, other: &Ulid) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<str> for Ulid {
+    /// Compares with the canonical string of this ULID, so lowercase letters and
+    /// aliases are never equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(ulid, "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// assert_ne!(ulid, "01cah7nxgrdjne9b1ny7pqgyv7");
+    /// ```
+    fn eq(&self, other: &str) -> bool {
+        self.to_ascii_bytes() == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for Ulid {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Ulid> for str {
+    fn eq(&self, other: &Ulid) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Ulid> for &str {
+    fn eq(&self, other: &Ulid) -> bool {
+        other == *self
+    }
+}
+
+impl PartialOrd<str> for Ulid {
+    /// Compares the canonical string of this ULID with `other` byte by byte.
+    ///
+    /// As all canonical strings have the same length, this is consistent with the
+    /// order of ULIDs, e.g. for filtering by string boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert!("01CAH7NXGR0000000000000000" < ulid);
+    /// assert!(ulid < "01CAH7NXGS0000000000000000");
+    /// ```
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.to_ascii_bytes()[..].cmp(other.as_bytes()))
+    }
+}
+
+impl PartialOrd<&str> for Ulid {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.partial_cmp(*other)
+    }
+}
+
+impl PartialOrd<Ulid> for str {
+    fn partial_cmp(&self, other: &Ulid) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl PartialOrd<Ulid> for &str {
+    fn partial_cmp(&self, other: &Ulid) -> Option<Ordering> {
+        other.partial_cmp(*self).map(Ordering::reverse)
+    }
+}
+
+impl FromStr for Ulid {
+    type Err = DecodingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = crockford::parse_crockford_u64_tuple(s)?;
+
+        Ok(Self::from(value))
+    }
+}
+
+impl From<[u8; 16]> for Ulid {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let bytes: [u8; 16] = [
+    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
+    /// ];
+    ///
+    /// let ulid = Ulid::from(bytes);
+    ///
+    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let bytes: [u8; 16] = [
+    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
+    /// ];
+    ///
+    /// let ulid : Ulid = bytes.into();
+    ///
+    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// ```
+    fn from(bytes: [u8; 16]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl From<Ulid> for [u8; 16] {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// let bytes = <[u8; 16]>::from(ulid);
+    ///
+    /// let expected_bytes: [u8; 16] = [
+    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
+    /// ];
+    ///
+    /// assert_eq!(bytes, expected_bytes);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// let bytes: [u8; 16] = ulid.into();
+    ///
+    /// let expected_bytes: [u8; 16] = [
+    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
+    /// ];
+    ///
+    /// assert_eq!(bytes, expected_bytes);
+    /// ```
+    #[rustfmt::skip]
+    fn from(ulid: Ulid) -> Self {
+        ulid.bytes
+    }
+}
+
+impl From<(u64, u64)> for Ulid {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
+    ///
+    /// let ulid = Ulid::from(tuple);
+    ///
+    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
+    ///
+    /// let ulid : Ulid = tuple.into();
+    ///
+    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// ```
+    fn from(value: (u64, u64)) -> Self {
+        Self::from_u64_tuple(value)
+    }
+}
+
+impl From<Ulid> for (u64, u64) {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// let tuple = <(u64, u64)>::from(ulid);
+    ///
+    /// let expected_tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(tuple, expected_tuple);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// let tuple : (u64, u64) = ulid.into();
+    ///
+    /// let expected_tuple = (0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(tuple, expected_tuple);
+    /// ```
+    fn from(ulid: Ulid) -> Self {
+        ulid.to_u64_tuple()
+    }
+}
+
+impl From<u128> for Ulid {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    ///
+    /// let ulid = Ulid::from(value);
+    ///
+    /// let expected_ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    ///
+    /// let ulid : Ulid = value.into();
+    ///
+    /// let expected_ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// ```
+    fn from(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+}
+
+impl From<Ulid> for u128 {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    ///
+    /// let value = <u128>::from(ulid);
+    ///
+    /// let expected_value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    ///
+    /// assert_eq!(value, expected_value);
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// let ulid = Ulid::from((0x1122_3344_5566_7788, 0x99AA_BBCC_DDEE_F00F));
+    ///
+    /// let value : u128 = ulid.into();
+    ///
+    /// let expected_value = 0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F;
+    ///
+    /// assert_eq!(value, expected_value);
+    /// ```
+    fn from(ulid: Ulid) -> Self {
+        ulid.to_u128()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Ulid> for String {
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    ///
+    /// fn label(id: impl Into<String>) -> String {
+    ///     id.into()
+    /// }
+    ///
+    /// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+    ///
+    /// assert_eq!(label(ulid), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+    /// ```
+    fn from(ulid: Ulid) -> Self {
+        ulid.to_string()
+    }
+}
+
+impl AsRef<[u8]> for Ulid {
+    /// Returns the 16 bytes of this ULID in big-endian byte order, see [`Ulid::as_bytes`].
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl TryFrom<&[u8]> for Ulid {
+    type Error = DecodingError;
+
+    /// Returns a ULID for the given slice of bytes or `DecodingError::InvalidLength`
+    /// if the slice does not contain exactly 16 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use std::convert::TryFrom;
+    /// use std::convert::TryInto;
+    ///
+    /// let bytes: [u8; 18] = [
+    ///     0x00,
+    ///     0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
+    ///     0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xF0, 0x0F,
+    ///     0x00,
+    /// ];
+    ///
+    /// let ulid : Ulid = Ulid::try_from(&bytes[1..17])?;
+    ///
+    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    ///
+    /// let ulid : Ulid = (&bytes[1..17]).try_into()?;
+    ///
+    /// let expected_ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+    ///
+    /// assert_eq!(ulid, expected_ulid);
+    /// # Ok::<(), rusty_ulid::DecodingError>(())
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use rusty_ulid::DecodingError;
+    /// use std::convert::TryFrom;
+    ///
+    /// let mut bytes: [u8; 17] = [0; 17];
+    /// let result = Ulid::try_from(&bytes[0..]);
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(DecodingError::InvalidLength {
+    ///         expected: 16,
+    ///         actual: 17
+    ///     })
+    /// )
+    /// ```
+    ///
+    /// ```
+    /// use rusty_ulid::Ulid;
+    /// use rusty_ulid::DecodingError;
+    /// use std::convert::TryFrom;
+    ///
+    /// let mut bytes: [u8; 15] = [0; 15];
+    /// let result = Ulid::try_from(&bytes[0..]);
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(DecodingError::InvalidLength {
+    ///         expected: 16,
+    ///         actual: 15
+    ///     })
+    /// )
+    /// ```
+    fn try_from(bytes: &[u8]) -> Result<Self, DecodingError> {
+        match <[u8; 16]>::try_from(bytes) {
+            Ok(bytes) => Ok(Self { bytes }),
+            Err(_) => Err(DecodingError::InvalidLength {
+                expected: 16,
+                actual: bytes.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Ulid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let bytes: [u8; 16] = (*self).into();
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Ulid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            struct UlidStringVisitor;
+
+            impl de::Visitor<'_> for UlidStringVisitor {
+                type Value = Ulid;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(formatter, "a ULID string")
+                }
+
+                fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
+                    value.parse::<Ulid>().map_err(E::custom)
+                }
+            }
+
+            deserializer.deserialize_str(UlidStringVisitor)
+        } else {
+            struct UlidBytesVisitor;
+
+            impl de::Visitor<'_> for UlidBytesVisitor {
+                type Value = Ulid;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(formatter, "16 ULID bytes")
+                }
+
+                fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Ulid, E> {
+                    Ulid::try_from(value).map_err(E::custom)
+                }
+            }
+            deserializer.deserialize_bytes(UlidBytesVisitor)
+        }
+    }
+}
+
+#[cfg(all(doctest, feature = "rand", feature = "chrono"))]
+mod doc_tests {
+    use doc_comment::doctest;
+    doctest!("../README.md", readme);
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Readable, Token};
+
+    #[test]
+    fn test_serde_readable() {
+        use serde_test::Configure;
+
+        let ulid = Ulid::from_str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap();
+        assert_tokens(
+            &ulid.readable(),
+            &[Token::Str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ")],
+        );
+
+        let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+        assert_tokens(
+            &ulid.readable(),
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")],
+        );
+    }
+
+    #[test]
+    fn test_serde_compact() {
+        use serde_test::Configure;
 
         let ulid = Ulid::from_str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap();
         assert_tokens(
@@ -1275,300 +3904,1224 @@ mod serde_tests {
         );
 
         let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
-        assert_tokens(
-            &ulid.compact(),
-            &[Token::Bytes(&[
-                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
-                0xF0, 0x0F,
-            ])],
+        assert_tokens(
+            &ulid.compact(),
+            &[Token::Bytes(&[
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+                0xF0, 0x0F,
+            ])],
+        );
+    }
+
+    #[test]
+    fn test_de_readable_error() {
+        assert_de_tokens_error::<Readable<Ulid>>(
+            &[Token::Bytes(&[
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+                0xF0, 0x0F,
+            ])],
+            "invalid type: byte array, expected a ULID string",
+        );
+
+        assert_de_tokens_error::<Readable<Ulid>>(
+            &[Token::Str("0H48SM8NB6EY49KANUSKEYXW0F")],
+            "invalid character 'U' at position 17",
+        );
+
+        assert_de_tokens_error::<Readable<Ulid>>(
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0FF")],
+            "invalid length 27, expected 26",
+        );
+
+        assert_de_tokens_error::<Readable<Ulid>>(
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0")],
+            "invalid length 25, expected 26",
+        );
+
+        assert_de_tokens_error::<Readable<Ulid>>(
+            &[Token::Str("80000000000000000000000000")],
+            "data type overflow",
+        );
+    }
+
+    #[test]
+    fn test_de_compact_error() {
+        assert_de_tokens_error::<Compact<Ulid>>(
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")],
+            "invalid type: string \"0H48SM8NB6EY49KANVSKEYXW0F\", expected 16 ULID bytes",
+        );
+
+        assert_de_tokens_error::<Compact<Ulid>>(
+            &[Token::Bytes(&[
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+                0xF0, 0x0F, 0xFF,
+            ])],
+            "invalid length 17, expected 16",
+        );
+
+        assert_de_tokens_error::<Compact<Ulid>>(
+            &[Token::Bytes(&[
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+                0xF0,
+            ])],
+            "invalid length 15, expected 16",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAST_TIMESTAMP: u64 = 1_481_195_424_879;
+    const PAST_TIMESTAMP_PART: &str = "01B3F2133F";
+
+    const MAX_TIMESTAMP: u64 = 0xFFFF_FFFF_FFFF;
+    const MAX_TIMESTAMP_PART: &str = "7ZZZZZZZZZ";
+
+    const MIN_TIMESTAMP: u64 = 0;
+    const MIN_TIMESTAMP_PART: &str = "0000000000";
+
+    #[test]
+    fn increment() {
+        single_increment(0x0000_0000_0000_0000_0000_0000_0000_0000, Ulid::from(1));
+        single_increment(
+            0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFE,
+            Ulid::from(0xFFFF_FFFF_FFFF_FFFF_FFFF),
+        );
+        single_increment(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF, Ulid::from(0));
+        single_increment(
+            0x0000_0000_0001_0000_0000_0000_0000_0000,
+            Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0001),
+        );
+        single_increment(
+            0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF,
+            Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0000),
+        );
+    }
+
+    fn single_increment(input: u128, expected_result: Ulid) {
+        let input_value: Ulid = input.into();
+        let incremented = input_value.increment();
+
+        assert_eq!(incremented, expected_result);
+        assert_eq!(input_value.timestamp(), incremented.timestamp());
+    }
+
+    #[test]
+    fn from_string_to_string() {
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "ZZZZZZZZZZZZZZZZ"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "123456789ABCDEFG"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "1000000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "1000000000000001"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "0001000000000001"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "0100000000000001"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000001"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(MAX_TIMESTAMP_PART.to_owned() + "123456789ABCDEFG"),
+            MAX_TIMESTAMP,
+        );
+        single_from_string_to_string(
+            &(MIN_TIMESTAMP_PART.to_owned() + "123456789ABCDEFG"),
+            MIN_TIMESTAMP,
+        );
+
+        let largest_legal_ulid_string = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ";
+        single_from_string_to_string(largest_legal_ulid_string, MAX_TIMESTAMP);
+    }
+
+    fn single_from_string_to_string(s: &str, timestamp: u64) {
+        let ulid = Ulid::from_str(s).unwrap();
+        assert_eq!(ulid.timestamp(), timestamp);
+        assert_eq!(ulid.to_string(), s);
+    }
+
+    #[test]
+    fn from_string_to_string_special_cases() {
+        single_from_string_to_string_special_case(
+            &(PAST_TIMESTAMP_PART.to_owned() + "00i0000000000000"),
+            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string_special_case(
+            &(PAST_TIMESTAMP_PART.to_owned() + "00I0000000000000"),
+            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string_special_case(
+            &(PAST_TIMESTAMP_PART.to_owned() + "00l0000000000000"),
+            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string_special_case(
+            &(PAST_TIMESTAMP_PART.to_owned() + "00L0000000000000"),
+            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string_special_case(
+            &(PAST_TIMESTAMP_PART.to_owned() + "00o0000000000000"),
+            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000000"),
+            PAST_TIMESTAMP,
+        );
+        single_from_string_to_string_special_case(
+            &(PAST_TIMESTAMP_PART.to_owned() + "00O0000000000000"),
+            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000000"),
+            PAST_TIMESTAMP,
+        );
+    }
+
+    fn single_from_string_to_string_special_case(s: &str, expected: &str, timestamp: u64) {
+        let ulid = Ulid::from_str(s).unwrap();
+        assert_eq!(ulid.timestamp(), timestamp);
+        assert_eq!(ulid.to_string(), expected);
+    }
+
+    #[test]
+    fn from_str_failure_too_long() {
+        let result = Ulid::from_str("123456789012345678901234567");
+        assert_eq!(
+            result,
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 27
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_failure_too_short() {
+        let result = Ulid::from_str("1234567890123456789012345");
+        assert_eq!(
+            result,
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_failure_invalid_unicode() {
+        let string = "012345678🦀0123456789012";
+        let result = Ulid::from_str(string);
+        assert_eq!(
+            result,
+            Err(DecodingError::InvalidChar {
+                character: '🦀',
+                position: 9,
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_failure_overflow() {
+        let smallest_overflowing_ulid_string = "80000000000000000000000000";
+        let result = Ulid::from_str(smallest_overflowing_ulid_string);
+        assert_eq!(result, Err(DecodingError::DataTypeOverflow));
+    }
+
+    #[test]
+    fn eq_cmp_sanity_checks() {
+        // yes, this is pretty paranoid.
+
+        use std::cmp::Ordering;
+
+        let ulid_one_low: Ulid = (0, 1).into();
+        let ulid_two_low: Ulid = (0, 2).into();
+        let ulid_one_high: Ulid = (1, 0).into();
+
+        let ulid_one_low_other: Ulid = (0, 1).into();
+
+        assert!(ulid_one_low.eq(&ulid_one_low));
+        assert_eq!(ulid_one_low.cmp(&ulid_one_low), Ordering::Equal);
+
+        assert_eq!(ulid_one_low, ulid_one_low_other);
+        assert!(ulid_one_low.eq(&ulid_one_low_other));
+        assert_eq!(ulid_one_low.cmp(&ulid_one_low_other), Ordering::Equal);
+
+        assert_ne!(ulid_one_low, ulid_two_low);
+        assert_ne!(ulid_two_low, ulid_one_low);
+        assert!(ulid_one_low < ulid_two_low);
+        assert!(ulid_two_low > ulid_one_low);
+        assert!(!ulid_one_low.eq(&ulid_two_low));
+        assert!(!ulid_two_low.eq(&ulid_one_low));
+        assert_eq!(ulid_one_low.cmp(&ulid_two_low), Ordering::Less);
+        assert_eq!(ulid_two_low.cmp(&ulid_one_low), Ordering::Greater);
+
+        assert_ne!(ulid_one_low, ulid_one_high);
+        assert_ne!(ulid_one_high, ulid_one_low);
+        assert!(!ulid_one_low.eq(&ulid_one_high));
+        assert!(!ulid_one_high.eq(&ulid_one_low));
+        assert_eq!(ulid_one_low.cmp(&ulid_one_high), Ordering::Less);
+        assert_eq!(ulid_one_high.cmp(&ulid_one_low), Ordering::Greater);
+    }
+
+    #[test]
+    fn hash_sanity_checks() {
+        // yes, this is also pretty paranoid.
+
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let ulid_one_low: Ulid = (0, 1).into();
+        let ulid_two_low: Ulid = (0, 2).into();
+        let ulid_one_high: Ulid = (1, 0).into();
+
+        let ulid_one_low_other: Ulid = (0, 1).into();
+
+        let mut hasher_one_low = DefaultHasher::new();
+        ulid_one_low.hash(&mut hasher_one_low);
+        let hash_one_low = hasher_one_low.finish();
+
+        let mut hasher_one_low_other = DefaultHasher::new();
+        ulid_one_low_other.hash(&mut hasher_one_low_other);
+        let hash_one_low_other = hasher_one_low_other.finish();
+
+        let mut hasher_two_low = DefaultHasher::new();
+        ulid_two_low.hash(&mut hasher_two_low);
+        let hash_two_low = hasher_two_low.finish();
+
+        let mut hasher_one_high = DefaultHasher::new();
+        ulid_one_high.hash(&mut hasher_one_high);
+        let hash_one_high = hasher_one_high.finish();
+
+        // this must be true
+        assert_eq!(hash_one_low, hash_one_low_other);
+
+        // this should be true in case of a reasonable DefaultHasher implementation
+        assert_ne!(hash_one_low, hash_two_low);
+        assert_ne!(hash_one_low, hash_one_high);
+    }
+
+    #[test]
+    fn fingerprint_test_cases() {
+        let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+        assert_eq!(ulid.fingerprint(), 0x7788);
+
+        let fingerprinted = ulid.with_fingerprint(0xABCD);
+        assert_eq!(fingerprinted.fingerprint(), 0xABCD);
+        assert_eq!(fingerprinted.timestamp(), ulid.timestamp());
+        assert_eq!(
+            fingerprinted,
+            Ulid::from(0x1122_3344_5566_ABCD_99AA_BBCC_DDEE_F00F)
+        );
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn next_monotonic_with_process_fingerprint_keeps_fingerprint() {
+        let mut ulid = Ulid::generate_with_process_fingerprint();
+        for _ in 0..100 {
+            ulid = Ulid::next_monotonic_with_process_fingerprint(ulid);
+            assert_eq!(ulid.fingerprint(), process_fingerprint());
+        }
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn generate_batch_shares_timestamp_and_increases() {
+        let ulids = Ulid::generate_batch(10_000);
+
+        assert_eq!(ulids.len(), 10_000);
+        assert!(ulids
+            .iter()
+            .all(|ulid| ulid.timestamp() == ulids[0].timestamp()));
+        assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(Ulid::generate_batch(0).is_empty());
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[test]
+    fn generate_batch_into_keeps_existing_elements() {
+        let existing = Ulid::from(u128::MAX);
+        let mut ulids = vec![existing];
+
+        Ulid::generate_batch_into(&mut ulids, 3);
+
+        assert_eq!(ulids.len(), 4);
+        assert_eq!(ulids[0], existing);
+        assert!(ulids[1..].windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn base32_words_round_trip() {
+        let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+
+        for n in 1..=26 {
+            let words = ulid.to_base32_words(n);
+            let groups: Vec<&str> = words.split('-').collect();
+
+            assert_eq!(groups.len(), 26 / n);
+            assert!(groups[1..].iter().all(|group| group.len() == n));
+            assert_eq!(groups[0].len(), n + 26 % n);
+            assert_eq!(Ulid::from_base32_words(&words), Ok(ulid));
+            assert_eq!(Ulid::parse_lenient(&words), Ok(ulid));
+            assert_eq!(Ulid::parse_lenient(&format!(" \"{words}\"\n")), Ok(ulid));
+            assert_eq!(
+                Ulid::from_str_with_mode(&words, ParseMode::Lenient),
+                Ok(ulid)
+            );
+        }
+    }
+
+    #[test]
+    fn from_base32_words_failures() {
+        assert_eq!(
+            Ulid::from_base32_words("0H48SM-8NB6E-Y49KA-NVSKE-YXW0"),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25
+            })
+        );
+        assert_eq!(
+            Ulid::from_base32_words("0H48SM-8NB6E-Y49KA-NUSKE-YXW0F"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 20,
+            })
+        );
+        assert_eq!(
+            Ulid::from_base32_words("0H48SM-8NB6E-Y49KA-N\u{C4}KE-YXW0F"),
+            Err(DecodingError::InvalidChar {
+                character: '\u{C4}',
+                position: 20,
+            })
+        );
+        assert_eq!(
+            Ulid::from_base32_words("0H48SM_8NB6E-Y49KA-NVSKE-YXW0F"),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 27
+            })
         );
     }
 
+    #[cfg(feature = "std")]
+    #[cfg(not(miri))] // expected panic
     #[test]
-    fn test_de_readable_error() {
-        assert_de_tokens_error::<Readable<Ulid>>(
-            &[Token::Bytes(&[
-                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
-                0xF0, 0x0F,
-            ])],
-            "invalid type: byte array, expected a ULID string",
+    #[should_panic(expected = "ULID words must contain between 1 and 26 characters")]
+    fn base32_words_zero() {
+        let _ = Ulid::from(0).to_base32_words(0);
+    }
+
+    #[test]
+    fn partial_from_prefix_test_cases() {
+        single_partial_from_prefix(
+            "0",
+            "00000000000000000000000000",
+            "0ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+        );
+        single_partial_from_prefix(
+            "7",
+            "70000000000000000000000000",
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+        );
+        single_partial_from_prefix(
+            "01b3f2133f",
+            "01B3F2133F0000000000000000",
+            "01B3F2133FZZZZZZZZZZZZZZZZ",
         );
+        single_partial_from_prefix(
+            "0H48SM8NB6EY49KANVSKEYXW0",
+            "0H48SM8NB6EY49KANVSKEYXW00",
+            "0H48SM8NB6EY49KANVSKEYXW0Z",
+        );
+        single_partial_from_prefix(
+            "0H48SM8NB6EY49KANVSKEYXW0F",
+            "0H48SM8NB6EY49KANVSKEYXW0F",
+            "0H48SM8NB6EY49KANVSKEYXW0F",
+        );
+    }
 
-        assert_de_tokens_error::<Readable<Ulid>>(
-            &[Token::Str("0H48SM8NB6EY49KANUSKEYXW0F")],
-            "invalid character 'U'",
+    fn single_partial_from_prefix(prefix: &str, expected_min: &str, expected_max: &str) {
+        let (min, max) = Ulid::partial_from_prefix(prefix).unwrap();
+        assert_eq!(min.to_string(), expected_min);
+        assert_eq!(max.to_string(), expected_max);
+    }
+
+    #[test]
+    fn partial_from_prefix_failures() {
+        assert_eq!(
+            Ulid::partial_from_prefix(""),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            Ulid::partial_from_prefix("123456789012345678901234567"),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 27
+            })
+        );
+        assert_eq!(
+            Ulid::partial_from_prefix("8"),
+            Err(DecodingError::DataTypeOverflow)
+        );
+        assert_eq!(
+            Ulid::partial_from_prefix("01U"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 2,
+            })
         );
+    }
 
-        assert_de_tokens_error::<Readable<Ulid>>(
-            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0FF")],
-            "invalid length",
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trip() {
+        let ulid = Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F);
+        let json = ulid.to_json_string();
+
+        assert_eq!(json, "\"0H48SM8NB6EY49KANVSKEYXW0F\"");
+        assert_eq!(Ulid::from_json_fragment(&json), Ok(ulid));
+        assert_eq!(
+            Ulid::from_json_fragment("\t\r\n \"0H48SM8NB6EY49KANVSKEYXW0F\" "),
+            Ok(ulid)
         );
+    }
 
-        assert_de_tokens_error::<Readable<Ulid>>(
-            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0")],
-            "invalid length",
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json_fragment_failures() {
+        assert_eq!(
+            Ulid::from_json_fragment(""),
+            Err(DecodingError::InvalidLength {
+                expected: 28,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            Ulid::from_json_fragment("\""),
+            Err(DecodingError::InvalidLength {
+                expected: 28,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            Ulid::from_json_fragment("0H48SM8NB6EY49KANVSKEYXW0F"),
+            Err(DecodingError::InvalidChar {
+                character: '0',
+                position: 0,
+            })
+        );
+        assert_eq!(
+            Ulid::from_json_fragment("\"0H48SM8NB6EY49KANVSKEYXW0F"),
+            Err(DecodingError::InvalidChar {
+                character: 'F',
+                position: 26,
+            })
+        );
+        assert_eq!(
+            Ulid::from_json_fragment(" \"0H48SM8NB6EY49KANUSKEYXW0F\""),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 19,
+            })
+        );
+        assert_eq!(
+            Ulid::from_json_fragment("\"0H48SM8NB6EY49KANVSKEYXW0\""),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25
+            })
+        );
+        assert_eq!(
+            Ulid::from_json_fragment("\"\\u0030H48SM8NB6EY49KANVSKEYXW0F\""),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 31
+            })
         );
+    }
 
-        assert_de_tokens_error::<Readable<Ulid>>(
-            &[Token::Str("80000000000000000000000000")],
-            "data type overflow",
+    #[test]
+    fn try_parse_const_test_cases() {
+        const MAX: Result<Ulid, ParseConstError> =
+            Ulid::try_parse_const("7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert_eq!(MAX, Ok(Ulid::from(u128::MAX)));
+
+        assert_eq!(
+            Ulid::try_parse_const("0h48sm8nb6ey49kanvskeyxw0f"),
+            Ok(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F))
+        );
+        assert_eq!(
+            Ulid::try_parse_const("0H48SM8NB6EY49KANVSKEYXW0"),
+            Err(ParseConstError::InvalidLength(25))
+        );
+        assert_eq!(
+            Ulid::try_parse_const("0H48SM8NB6EY49KANUSKEYXW0F"),
+            Err(ParseConstError::InvalidChar(17))
+        );
+        assert_eq!(
+            Ulid::try_parse_const("012345678🦀0123456789012"),
+            Err(ParseConstError::InvalidChar(9))
+        );
+        assert_eq!(
+            Ulid::try_parse_const("80000000000000000000000000"),
+            Err(ParseConstError::DataTypeOverflow)
         );
     }
 
     #[test]
-    fn test_de_compact_error() {
-        assert_de_tokens_error::<Compact<Ulid>>(
-            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")],
-            "invalid type: string \"0H48SM8NB6EY49KANVSKEYXW0F\", expected 16 ULID bytes",
+    fn from_ascii_slice_matches_from_str() {
+        let ulid = Ulid::from_ascii_slice(b"0h48sm8nb6ey49kanvskeyxw0f");
+        assert_eq!(ulid, "0h48sm8nb6ey49kanvskeyxw0f".parse());
+
+        assert_eq!(
+            Ulid::from_ascii_slice(b""),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            Ulid::from_ascii_slice(b"0H48SM8NB6EY49KANUSKEYXW0F"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 17,
+            })
+        );
+        assert_eq!(
+            Ulid::from_ascii_slice(b"0H48SM8NB6EY49KAN\xFFSKEYXW0F"),
+            Err(DecodingError::InvalidChar {
+                character: '\u{FF}',
+                position: 17,
+            })
         );
+    }
 
-        assert_de_tokens_error::<Compact<Ulid>>(
-            &[Token::Bytes(&[
-                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
-                0xF0, 0x0F, 0xFF,
-            ])],
-            "invalid length",
+    #[test]
+    fn parse_lenient_trims_whitespace_and_quotes() {
+        let expected = "0H48SM8NB6EY49KANVSKEYXW0F".parse();
+
+        for input in [
+            "0H48SM8NB6EY49KANVSKEYXW0F",
+            "0H48SM8NB6EY49KANVSKEYXW0F\n",
+            "\t0H48SM8NB6EY49KANVSKEYXW0F\r\n",
+            "\"0H48SM8NB6EY49KANVSKEYXW0F\"",
+            "'0H48SM8NB6EY49KANVSKEYXW0F'",
+            " \" '0H48SM8NB6EY49KANVSKEYXW0F' \" ",
+        ] {
+            assert_eq!(Ulid::parse_lenient(input), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn parse_lenient_failures() {
+        assert_eq!(
+            Ulid::parse_lenient("\"0H48SM8NB6EY49KANVSKEYXW0F'"),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 28
+            })
+        );
+        assert_eq!(
+            Ulid::parse_lenient("\""),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            Ulid::parse_lenient(" \"0H48SM8NB6EY49KANUSKEYXW0F\""),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 19,
+            })
         );
+        assert_eq!(
+            Ulid::parse_lenient(" '0H48SM-8NB6E-Y49KA-NUSKE-YXW0F'"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 22,
+            })
+        );
+    }
 
-        assert_de_tokens_error::<Compact<Ulid>>(
-            &[Token::Bytes(&[
-                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
-                0xF0,
-            ])],
-            "invalid length",
+    #[cfg(feature = "std")]
+    #[test]
+    fn urn_round_trip() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        assert_eq!(ulid.to_urn(), "ulid:01CAH7NXGRDJNE9B1NY7PQGYV7");
+        assert_eq!(Ulid::from_urn(&ulid.to_urn()), Ok(ulid));
+        assert_eq!(Ulid::from_urn("Ulid:01cah7nxgrdjne9b1ny7pqgyv7"), Ok(ulid));
+    }
+
+    #[test]
+    fn from_urn_failures() {
+        assert_eq!(
+            Ulid::from_urn("01CAH7NXGRDJNE9B1NY7PQGYV7"),
+            Err(DecodingError::InvalidChar {
+                character: '0',
+                position: 0,
+            })
+        );
+        assert_eq!(
+            Ulid::from_urn("uuid:01CAH7NXGRDJNE9B1NY7PQGYV7"),
+            Err(DecodingError::InvalidChar {
+                character: 'u',
+                position: 1,
+            })
+        );
+        assert_eq!(
+            Ulid::from_urn("uli"),
+            Err(DecodingError::InvalidLength {
+                expected: 31,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            Ulid::from_urn("ulid:01CAH7NXGRDJNE9B1NY7PQGYV"),
+            Err(DecodingError::InvalidLength {
+                expected: 26,
+                actual: 25
+            })
+        );
+        assert_eq!(
+            Ulid::from_urn("ulid:01CAH7NXGRDJNE9B1NY7PQGYVU"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 30,
+            })
+        );
+    }
+
+    #[test]
+    fn timestamp_comparisons_ignore_random_part() {
+        let smaller = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
+        let larger = Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0000);
+
+        assert_eq!(smaller.cmp_timestamp(&larger), Ordering::Less);
+        assert_eq!(larger.cmp_timestamp(&smaller), Ordering::Greater);
+        assert_eq!(larger.cmp_timestamp(&larger.increment()), Ordering::Equal);
+        assert!(larger.same_millisecond(&larger.increment()));
+        assert!(!smaller.same_millisecond(&larger));
+    }
+
+    #[test]
+    fn timestamp_boundaries() {
+        for timestamp in [0, 1_523_144_390_168, 0xFFFF_FFFF_FFFF] {
+            let min = Ulid::min_for_timestamp(timestamp);
+            let max = Ulid::max_for_timestamp(timestamp);
+
+            assert_eq!(min.timestamp(), timestamp);
+            assert_eq!(max.timestamp(), timestamp);
+            assert_eq!(max.increment(), min);
+        }
+        assert_eq!(Ulid::min_for_timestamp(0), Ulid::from(0));
+        assert_eq!(
+            Ulid::max_for_timestamp(0xFFFF_FFFF_FFFF),
+            Ulid::from(u128::MAX)
+        );
+    }
+
+    #[cfg(not(miri))] // expected panic
+    #[test]
+    #[should_panic(expected = "ULID does not support timestamps after +10889-08-02T05:31:50.655Z")]
+    fn min_for_timestamp_panics_on_overflow() {
+        let _ = Ulid::min_for_timestamp(0x0001_0000_0000_0000);
+    }
+
+    #[test]
+    fn range_for_timestamps() {
+        let range = Ulid::range_for(0..1);
+        assert_eq!(range, Ulid::from(0)..=Ulid::max_for_timestamp(0));
+
+        let range = Ulid::range_for(0..u64::MAX);
+        assert_eq!(range, Ulid::from(0)..=Ulid::from(u128::MAX));
+
+        assert!(Ulid::range_for(1..1).is_empty());
+        assert!(Ulid::range_for(u64::MAX - 1..u64::MAX).is_empty());
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn range_for_nanos_rounds_to_milliseconds() {
+        assert_eq!(
+            Ulid::range_for_nanos(1_500_000..2_000_001),
+            Ulid::range_for(1..3)
+        );
+        assert_eq!(
+            Ulid::range_for_nanos(-1_000_000..1_000_000),
+            Ulid::range_for(0..1)
         );
+        assert_eq!(
+            Ulid::range_for_nanos(1_000_001..1_000_002),
+            Ulid::range_for(1..2)
+        );
+        assert!(Ulid::range_for_nanos(-2..-1).is_empty());
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const PAST_TIMESTAMP: u64 = 1_481_195_424_879;
-    const PAST_TIMESTAMP_PART: &str = "01B3F2133F";
 
-    const MAX_TIMESTAMP: u64 = 0xFFFF_FFFF_FFFF;
-    const MAX_TIMESTAMP_PART: &str = "7ZZZZZZZZZ";
+    #[cfg(feature = "std")]
+    #[test]
+    fn base64_round_trip() {
+        for value in [0, 1, 0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67, u128::MAX] {
+            let ulid = Ulid::from(value);
+            let string = ulid.to_base64();
 
-    const MIN_TIMESTAMP: u64 = 0;
-    const MIN_TIMESTAMP_PART: &str = "0000000000";
+            assert_eq!(string.len(), 22);
+            assert_eq!(Ulid::from_base64(&string), Ok(ulid));
+        }
+        assert_eq!(Ulid::from(0).to_base64(), "AAAAAAAAAAAAAAAAAAAAAA");
+        assert_eq!(Ulid::from(u128::MAX).to_base64(), "_____________________w");
+    }
 
     #[test]
-    fn increment() {
-        single_increment(0x0000_0000_0000_0000_0000_0000_0000_0000, Ulid::from(1));
-        single_increment(
-            0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFE,
-            Ulid::from(0xFFFF_FFFF_FFFF_FFFF_FFFF),
+    fn from_base64_failures() {
+        assert_eq!(
+            Ulid::from_base64("AWKievYYbKrkrDXx7Xh7Zw=="),
+            Err(DecodingError::InvalidLength {
+                expected: 22,
+                actual: 24
+            })
         );
-        single_increment(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF, Ulid::from(0));
-        single_increment(
-            0x0000_0000_0001_0000_0000_0000_0000_0000,
-            Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0001),
+        assert_eq!(
+            Ulid::from_base64("AWKievYYbKrkrDXx7Xh+Zw"),
+            Err(DecodingError::InvalidChar {
+                character: '+',
+                position: 19,
+            })
         );
-        single_increment(
-            0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF,
-            Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0000),
+        assert_eq!(
+            Ulid::from_base64("AWKievYYbKrkrDXx7Xh7Zx"),
+            Err(DecodingError::DataTypeOverflow)
         );
     }
 
-    fn single_increment(input: u128, expected_result: Ulid) {
-        let input_value: Ulid = input.into();
-        let incremented = input_value.increment();
+    #[cfg(feature = "std")]
+    #[test]
+    fn hex_round_trip() {
+        for value in [0, 1, 0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67, u128::MAX] {
+            let ulid = Ulid::from(value);
 
-        assert_eq!(incremented, expected_result);
-        assert_eq!(input_value.timestamp(), incremented.timestamp());
+            assert_eq!(ulid.to_hex(), format!("{ulid:032x}"));
+            assert_eq!(Ulid::from_hex(&ulid.to_hex()), Ok(ulid));
+            assert_eq!(Ulid::from_hex(&format!("{ulid:032X}")), Ok(ulid));
+        }
     }
 
     #[test]
-    fn from_string_to_string() {
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000000"),
-            PAST_TIMESTAMP,
+    fn from_hex_failures() {
+        assert_eq!(
+            Ulid::from_hex("0162a27af6186caae4ac35f1ed787b6"),
+            Err(DecodingError::InvalidLength {
+                expected: 32,
+                actual: 31
+            })
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "ZZZZZZZZZZZZZZZZ"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_hex("+162a27af6186caae4ac35f1ed787b67"),
+            Err(DecodingError::InvalidChar {
+                character: '+',
+                position: 0,
+            })
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "123456789ABCDEFG"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_hex("0162a27af6186caae4ac35f1ed787b6g"),
+            Err(DecodingError::InvalidChar {
+                character: 'g',
+                position: 31,
+            })
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "1000000000000000"),
-            PAST_TIMESTAMP,
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn deterministic_random_part_is_truncated_sha256() {
+        // SHA-256 of 16 zero bytes starts with 374708fff7719dd5979e
+        let ulid = Ulid::new_deterministic_with_timestamp(1, Ulid::from(0), b"");
+
+        assert_eq!(ulid, Ulid::from(0x0000_0000_0001_3747_08FF_F771_9DD5_979E));
+        assert_eq!(
+            Ulid::new_deterministic(Ulid::from(0), b""),
+            Ulid::from(0x0000_0000_0000_3747_08FF_F771_9DD5_979E)
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "1000000000000001"),
-            PAST_TIMESTAMP,
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn uuid_string_test_cases() {
+        assert_eq!(
+            Ulid::from(0).to_uuid_string(),
+            "00000000-0000-0000-0000-000000000000"
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "0001000000000001"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F).to_uuid_string(),
+            "11223344-5566-7788-99aa-bbccddeef00f"
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "0100000000000001"),
-            PAST_TIMESTAMP,
+    }
+
+    #[test]
+    fn from_uuid_str_test_cases() {
+        assert_eq!(
+            Ulid::from_uuid_str("11223344-5566-7788-99aa-bbccddeef00f"),
+            Ok(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F))
         );
-        single_from_string_to_string(
-            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000001"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_uuid_str("11223344-5566-7788-99AA-BBCCDDEEF00F"),
+            Ok(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F))
         );
-        single_from_string_to_string(
-            &(MAX_TIMESTAMP_PART.to_owned() + "123456789ABCDEFG"),
-            MAX_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_uuid_str("11223344-5566-7788-99aa-bbccddeef00"),
+            Err(DecodingError::InvalidLength {
+                expected: 36,
+                actual: 35
+            })
         );
-        single_from_string_to_string(
-            &(MIN_TIMESTAMP_PART.to_owned() + "123456789ABCDEFG"),
-            MIN_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_uuid_str("11223344_5566-7788-99aa-bbccddeef00f"),
+            Err(DecodingError::InvalidChar {
+                character: '_',
+                position: 8,
+            })
+        );
+        assert_eq!(
+            Ulid::from_uuid_str("11223344-5566-7788-99aa-bbccddeef00g"),
+            Err(DecodingError::InvalidChar {
+                character: 'g',
+                position: 35,
+            })
         );
-
-        let largest_legal_ulid_string = "7ZZZZZZZZZZZZZZZZZZZZZZZZZ";
-        single_from_string_to_string(largest_legal_ulid_string, MAX_TIMESTAMP);
-    }
-
-    fn single_from_string_to_string(s: &str, timestamp: u64) {
-        let ulid = Ulid::from_str(s).unwrap();
-        assert_eq!(ulid.timestamp(), timestamp);
-        assert_eq!(ulid.to_string(), s);
     }
 
     #[test]
-    fn from_string_to_string_special_cases() {
-        single_from_string_to_string_special_case(
-            &(PAST_TIMESTAMP_PART.to_owned() + "00i0000000000000"),
-            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
-            PAST_TIMESTAMP,
+    fn from_str_strict_rejects_non_canonical_characters() {
+        assert_eq!(
+            Ulid::from_str_strict("01CAH7NXGRDJNE9B1NY7PQGYV7"),
+            Ok(Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67))
         );
-        single_from_string_to_string_special_case(
-            &(PAST_TIMESTAMP_PART.to_owned() + "00I0000000000000"),
-            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_str_strict("O1CAH7NXGRDJNE9B1NY7PQGYV7"),
+            Err(DecodingError::InvalidChar {
+                character: 'O',
+                position: 0
+            })
         );
-        single_from_string_to_string_special_case(
-            &(PAST_TIMESTAMP_PART.to_owned() + "00l0000000000000"),
-            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_str_strict("01CAH7NXGRDJNE9B1NY7PQGYVi"),
+            Err(DecodingError::InvalidChar {
+                character: 'i',
+                position: 25
+            })
         );
-        single_from_string_to_string_special_case(
-            &(PAST_TIMESTAMP_PART.to_owned() + "00L0000000000000"),
-            &(PAST_TIMESTAMP_PART.to_owned() + "0010000000000000"),
-            PAST_TIMESTAMP,
+        // errors of from_str take precedence
+        assert_eq!(
+            Ulid::from_str_strict("01cAH7NXGRDJNE9B1NY7PQGYVU"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25
+            })
         );
-        single_from_string_to_string_special_case(
-            &(PAST_TIMESTAMP_PART.to_owned() + "00o0000000000000"),
-            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000000"),
-            PAST_TIMESTAMP,
+        assert_eq!(
+            Ulid::from_str_with_mode("O1cAH7NXGRDJNE9B1NY7PQGYVl", ParseMode::Lenient),
+            Ok(Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B61))
         );
-        single_from_string_to_string_special_case(
-            &(PAST_TIMESTAMP_PART.to_owned() + "00O0000000000000"),
-            &(PAST_TIMESTAMP_PART.to_owned() + "0000000000000000"),
-            PAST_TIMESTAMP,
+        assert_eq!(ParseMode::default(), ParseMode::Lenient);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn canonicalize_test_cases() {
+        assert!(Ulid::is_canonical("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"));
+        assert!(!Ulid::is_canonical("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"));
+        assert!(!Ulid::is_canonical("7ZZZZZZZZZZZZZZZZZZZZZZZZ"));
+        assert!(!Ulid::is_canonical("7ZZZZZZZZZZZZZZZZZZZZZZZZz"));
+
+        assert_eq!(
+            Ulid::canonicalize("OiLozzzzzzzzzzzzzzzzzzzzzz"),
+            Ok(Cow::Owned("0110ZZZZZZZZZZZZZZZZZZZZZZ".to_string()))
+        );
+        assert_eq!(
+            Ulid::canonicalize("0110ZZZZZZZZZZZZZZZZZZZZZZ"),
+            Ok(Cow::Borrowed("0110ZZZZZZZZZZZZZZZZZZZZZZ"))
+        );
+        assert_eq!(
+            Ulid::canonicalize("0110ZZZZZZZZZZZZZZZZZZZZZU"),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25
+            })
         );
     }
 
-    fn single_from_string_to_string_special_case(s: &str, expected: &str, timestamp: u64) {
-        let ulid = Ulid::from_str(s).unwrap();
-        assert_eq!(ulid.timestamp(), timestamp);
-        assert_eq!(ulid.to_string(), expected);
+    #[test]
+    fn i128_conversion_preserves_order() {
+        let values = [
+            0,
+            1,
+            0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF,
+            0x8000_0000_0000_0000_0000_0000_0000_0000,
+            u128::MAX,
+        ];
+
+        let converted: Vec<i128> = values.iter().map(|&v| Ulid::from(v).to_i128()).collect();
+
+        assert!(converted.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(converted[2], -1);
+        assert_eq!(converted[3], 0);
+        for (value, signed) in values.iter().zip(converted) {
+            assert_eq!(Ulid::from_i128(signed), Ulid::from(*value));
+        }
     }
 
+    #[cfg(any(
+        feature = "critical-section",
+        feature = "portable-atomic",
+        all(
+            any(feature = "rand", feature = "rand09", feature = "getrandom"),
+            any(feature = "chrono", feature = "time")
+        )
+    ))]
     #[test]
-    fn from_str_failure_too_long() {
-        let result = Ulid::from_str("123456789012345678901234567");
-        assert_eq!(result, Err(DecodingError::InvalidLength));
+    fn strictly_increasing_increments_same_millisecond() {
+        let previous_ulid = Ulid::from(0x0000_0000_0001_0000_0000_0000_0000_0000);
+
+        assert_eq!(
+            Ulid::next_strictly_increasing(Some(previous_ulid), 1, || (0xFFFF, 0)),
+            previous_ulid.increment()
+        );
+        assert_eq!(
+            Ulid::next_strictly_increasing(Some(previous_ulid), 0, || (0xFFFF, 0)),
+            previous_ulid.increment()
+        );
     }
 
+    #[cfg(any(
+        feature = "critical-section",
+        feature = "portable-atomic",
+        all(
+            any(feature = "rand", feature = "rand09", feature = "getrandom"),
+            any(feature = "chrono", feature = "time")
+        )
+    ))]
     #[test]
-    fn from_str_failure_too_short() {
-        let result = Ulid::from_str("1234567890123456789012345");
-        assert_eq!(result, Err(DecodingError::InvalidLength));
+    fn strictly_increasing_uses_random_bits_for_new_millisecond() {
+        assert_eq!(
+            Ulid::next_strictly_increasing(None, 1, || (0xABCD, 42)),
+            Ulid::from(0x0000_0000_0001_ABCD_0000_0000_0000_002A)
+        );
+        assert_eq!(
+            Ulid::next_strictly_increasing(Some(Ulid::from(0)), 1, || (0xABCD, 42)),
+            Ulid::from(0x0000_0000_0001_ABCD_0000_0000_0000_002A)
+        );
     }
 
+    #[cfg(any(
+        feature = "critical-section",
+        feature = "portable-atomic",
+        all(
+            any(feature = "rand", feature = "rand09", feature = "getrandom"),
+            any(feature = "chrono", feature = "time")
+        )
+    ))]
     #[test]
-    fn from_str_failure_invalid_unicode() {
-        let string = "012345678🦀0123456789012";
-        let result = Ulid::from_str(string);
-        assert_eq!(result, Err(DecodingError::InvalidChar('🦀')));
+    fn strictly_increasing_borrows_next_millisecond() {
+        let previous_ulid = Ulid::max_for_timestamp(1);
+
+        assert_eq!(
+            Ulid::next_strictly_increasing(Some(previous_ulid), 1, || (0, 7)),
+            Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0007)
+        );
     }
 
     #[test]
-    fn from_str_failure_overflow() {
-        let smallest_overflowing_ulid_string = "80000000000000000000000000";
-        let result = Ulid::from_str(smallest_overflowing_ulid_string);
-        assert_eq!(result, Err(DecodingError::DataTypeOverflow));
+    fn i64_pair_conversion_preserves_order() {
+        let values = [
+            0,
+            0x7FFF_FFFF_FFFF_FFFF,
+            0x8000_0000_0000_0000,
+            0x0000_0000_0000_0001_0000_0000_0000_0000,
+            0x8000_0000_0000_0000_0000_0000_0000_0000,
+            u128::MAX,
+        ];
+
+        let converted: Vec<(i64, i64)> = values
+            .iter()
+            .map(|&v| Ulid::from(v).to_i64_pair())
+            .collect();
+
+        assert!(converted.windows(2).all(|pair| pair[0] < pair[1]));
+        for (value, pair) in values.iter().zip(converted) {
+            assert_eq!(Ulid::from_i64_pair(pair), Ulid::from(*value));
+        }
     }
 
     #[test]
-    fn eq_cmp_sanity_checks() {
-        // yes, this is pretty paranoid.
+    fn cross_type_comparisons() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let string = ulid.to_string();
+
+        assert!(ulid == 0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        assert!(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67 == ulid);
+        assert!(ulid != 0);
+        assert!(ulid == ulid.to_be_bytes());
+        assert!(ulid.to_be_bytes() == ulid);
+        assert!(ulid != ulid.to_le_bytes());
+        assert!(ulid == *string);
+        assert!(*string == ulid);
+        assert!(ulid == string.as_str());
+        assert!(ulid != "01CAH7NXGRDJNE9B1NY7PQGYV");
+        assert!(ulid != "01CAH7NXGRDJNE9B1NY7PQGYV77");
+
+        assert_eq!(ulid.partial_cmp(string.as_str()), Some(Ordering::Equal));
+        assert!(ulid > "01CAH7NXGRDJNE9B1NY7PQGYV");
+        assert!(ulid < "01CAH7NXGRDJNE9B1NY7PQGYV77");
+        assert!("01CAH7NXGRDJNE9B1NY7PQGYV8" > ulid);
+        assert!(*"01CAH7NXGRDJNE9B1NY7PQGYV6" < ulid);
+    }
 
-        use std::cmp::Ordering;
+    #[test]
+    fn as_bytes_borrows_big_endian_bytes() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
 
-        let ulid_one_low: Ulid = (0, 1).into();
-        let ulid_two_low: Ulid = (0, 2).into();
-        let ulid_one_high: Ulid = (1, 0).into();
+        assert_eq!(ulid.as_bytes(), &ulid.to_be_bytes());
+        assert_eq!(ulid.as_ref(), &ulid.to_be_bytes()[..]);
+    }
 
-        let ulid_one_low_other: Ulid = (0, 1).into();
+    #[test]
+    fn byte_order_round_trip() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
 
-        assert!(ulid_one_low.eq(&ulid_one_low));
-        assert_eq!(ulid_one_low.cmp(&ulid_one_low), Ordering::Equal);
+        assert_eq!(ulid.to_be_bytes(), <[u8; 16]>::from(ulid));
+        assert_eq!(Ulid::from_be_bytes(ulid.to_be_bytes()), ulid);
+        assert_eq!(Ulid::from_le_bytes(ulid.to_le_bytes()), ulid);
 
-        assert_eq!(ulid_one_low, ulid_one_low_other);
-        assert!(ulid_one_low.eq(&ulid_one_low_other));
-        assert_eq!(ulid_one_low.cmp(&ulid_one_low_other), Ordering::Equal);
+        let mut reversed = ulid.to_be_bytes();
+        reversed.reverse();
+        assert_eq!(ulid.to_le_bytes(), reversed);
+    }
 
-        assert_ne!(ulid_one_low, ulid_two_low);
-        assert_ne!(ulid_two_low, ulid_one_low);
-        assert!(ulid_one_low < ulid_two_low);
-        assert!(ulid_two_low > ulid_one_low);
-        assert!(!ulid_one_low.eq(&ulid_two_low));
-        assert!(!ulid_two_low.eq(&ulid_one_low));
-        assert_eq!(ulid_one_low.cmp(&ulid_two_low), Ordering::Less);
-        assert_eq!(ulid_two_low.cmp(&ulid_one_low), Ordering::Greater);
+    #[test]
+    fn parse_const_error_messages() {
+        for (position, message) in INVALID_CHAR_MESSAGES.iter().enumerate() {
+            assert_eq!(ParseConstError::InvalidChar(position).message(), *message);
+            assert!(message.ends_with(&format!(" {position}")));
+        }
 
-        assert_ne!(ulid_one_low, ulid_one_high);
-        assert_ne!(ulid_one_high, ulid_one_low);
-        assert!(!ulid_one_low.eq(&ulid_one_high));
-        assert!(!ulid_one_high.eq(&ulid_one_low));
-        assert_eq!(ulid_one_low.cmp(&ulid_one_high), Ordering::Less);
-        assert_eq!(ulid_one_high.cmp(&ulid_one_low), Ordering::Greater);
+        assert_eq!(
+            ParseConstError::InvalidLength(25).to_string(),
+            "invalid length 25, expected 26"
+        );
+        assert_eq!(
+            ParseConstError::InvalidChar(17).to_string(),
+            "invalid character at position 17"
+        );
+        assert_eq!(
+            ParseConstError::DataTypeOverflow.to_string(),
+            "data type overflow"
+        );
     }
 
+    #[cfg(not(miri))] // expected panic
     #[test]
-    fn hash_sanity_checks() {
-        // yes, this is also pretty paranoid.
+    #[should_panic(expected = "invalid ULID: invalid character at position 17")]
+    fn parse_const_panic() {
+        let _ = Ulid::parse_const("0H48SM8NB6EY49KANUSKEYXW0F");
+    }
 
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+    #[test]
+    fn uuid_v7_conversions() {
+        let ulid = Ulid::from(0);
+        assert_eq!(
+            u128::from_be_bytes(ulid.to_uuid_v7()),
+            0x0000_0000_0000_7000_8000_0000_0000_0000
+        );
 
-        let ulid_one_low: Ulid = (0, 1).into();
-        let ulid_two_low: Ulid = (0, 2).into();
-        let ulid_one_high: Ulid = (1, 0).into();
+        let ulid = Ulid::from(0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF);
+        assert_eq!(
+            u128::from_be_bytes(ulid.to_uuid_v7()),
+            0xFFFF_FFFF_FFFF_7FFF_BFFF_FFFF_FFFF_FFFF
+        );
 
-        let ulid_one_low_other: Ulid = (0, 1).into();
+        let ulid = Ulid::from(0x0189_4B1E_1A2B_8000_0000_0000_0000_0040);
+        assert_eq!(
+            u128::from_be_bytes(ulid.to_uuid_v7()),
+            0x0189_4B1E_1A2B_7800_8000_0000_0000_0001
+        );
 
-        let mut hasher_one_low = DefaultHasher::new();
-        ulid_one_low.hash(&mut hasher_one_low);
-        let hash_one_low = hasher_one_low.finish();
+        let uuid = 0x0189_4B1E_1A2B_7123_8456_789A_BCDE_F012_u128.to_be_bytes();
+        let ulid = Ulid::from_uuid_v7(uuid).unwrap();
+        assert_eq!(ulid, Ulid::from(0x0189_4B1E_1A2B_1231_159E_26AF_37BC_0480));
+        assert_eq!(ulid.to_uuid_v7(), uuid);
 
-        let mut hasher_one_low_other = DefaultHasher::new();
-        ulid_one_low_other.hash(&mut hasher_one_low_other);
-        let hash_one_low_other = hasher_one_low_other.finish();
+        // wrong version
+        let uuid = 0x0189_4B1E_1A2B_4123_8456_789A_BCDE_F012_u128.to_be_bytes();
+        assert_eq!(Ulid::from_uuid_v7(uuid), None);
 
-        let mut hasher_two_low = DefaultHasher::new();
-        ulid_two_low.hash(&mut hasher_two_low);
-        let hash_two_low = hasher_two_low.finish();
+        // wrong variant
+        let uuid = 0x0189_4B1E_1A2B_7123_C456_789A_BCDE_F012_u128.to_be_bytes();
+        assert_eq!(Ulid::from_uuid_v7(uuid), None);
+    }
 
-        let mut hasher_one_high = DefaultHasher::new();
-        ulid_one_high.hash(&mut hasher_one_high);
-        let hash_one_high = hasher_one_high.finish();
+    #[test]
+    fn uuid_v7_preserves_timestamp_order() {
+        let earlier = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
+        let later = Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0000);
 
-        // this must be true
-        assert_eq!(hash_one_low, hash_one_low_other);
+        assert!(earlier.to_uuid_v7() < later.to_uuid_v7());
+    }
 
-        // this should be true in case of a reasonable DefaultHasher implementation
-        assert_ne!(hash_one_low, hash_two_low);
-        assert_ne!(hash_one_low, hash_one_high);
+    #[test]
+    fn uuid_v7_preserves_random_order() {
+        let ulids = [
+            Ulid::from(0x0189_4B1E_1A2B_0000_0000_0000_0000_0000),
+            Ulid::from(0x0189_4B1E_1A2B_0000_0000_0000_0000_0040),
+            Ulid::from(0x0189_4B1E_1A2B_3FFF_FFFF_FFFF_FFFF_FFC0),
+            Ulid::from(0x0189_4B1E_1A2B_4000_0000_0000_0000_0000),
+            Ulid::from(0x0189_4B1E_1A2B_7FFF_FFFF_FFFF_FFFF_FFC0),
+            Ulid::from(0x0189_4B1E_1A2B_8000_0000_0000_0000_0000),
+            Ulid::from(0x0189_4B1E_1A2B_FFFF_FFFF_FFFF_FFFF_FFC0),
+        ];
+
+        for pair in ulids.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].to_uuid_v7() < pair[1].to_uuid_v7());
+        }
+
+        for ulid in ulids {
+            assert_eq!(Ulid::from_uuid_v7(ulid.to_uuid_v7()), Some(ulid));
+        }
     }
 
     #[cfg(not(miri))] // expected panic
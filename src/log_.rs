@@ -0,0 +1,71 @@
+//! Key-value support for the [log](https://docs.rs/log) crate.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `log-kv` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["log-kv"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`ToValue`], so it can be passed as value of the
+//! structured key-values of the `log` macros and is formatted as canonical
+//! string by the backend:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let request_id = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! log::info!(request_id = request_id; "request received");
+//! ```
+//!
+//! `ToKey` isn't implemented because a [`Key`](log::kv::Key) has to borrow a
+//! string, which a `Ulid` doesn't contain. Use the canonical string of a ULID
+//! as key instead, e.g. `ulid.to_string()`.
+
+use crate::Ulid;
+use log::kv::{ToValue, Value};
+
+impl ToValue for Ulid {
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ulid;
+    use log::kv::{Error, Key, Source, ToValue, Value, VisitSource};
+
+    struct Collect(Vec<(String, String)>);
+
+    impl<'kvs> VisitSource<'kvs> for Collect {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+            self.0.push((key.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn value_is_canonical_string() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        assert_eq!(ulid.to_value().to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+
+        let mut collect = Collect(Vec::new());
+        ("request_id", ulid).visit(&mut collect).unwrap();
+        assert_eq!(
+            collect.0,
+            [(
+                String::from("request_id"),
+                String::from("01CAH7NXGRDJNE9B1NY7PQGYV7")
+            )]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! [minicbor](https://docs.rs/minicbor) CBOR encoding support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `minicbor` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["minicbor"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Encode`] and [`Decode`] as a 16-byte CBOR byte string
+//! using the same big-endian byte order as `<[u8; 16]>::from(ulid)`:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let bytes = minicbor::to_vec(ulid).unwrap();
+//! assert_eq!(bytes.len(), 17);
+//! assert_eq!(bytes[0], 0x50);
+//! assert_eq!(bytes[1..], <[u8; 16]>::from(ulid));
+//!
+//! let decoded: Ulid = minicbor::decode(&bytes).unwrap();
+//! assert_eq!(decoded, ulid);
+//! ```
+//!
+//! Use the [`tagged`] module to prefix the byte string with a CBOR tag.
+
+use crate::Ulid;
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{Decode, Encode};
+
+impl<C> Encode<C> for Ulid {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.bytes(&<[u8; 16]>::from(*self))?.ok()
+    }
+}
+
+impl<'b, C> Decode<'b, C> for Ulid {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        let position = d.position();
+        let bytes = <[u8; 16]>::try_from(d.bytes()?)
+            .map_err(|_| decode::Error::message("expected 16 bytes for ULID").at(position))?;
+        Ok(Self::from(bytes))
+    }
+}
+
+/// Encodes a `Ulid` as a tagged 16-byte CBOR byte string.
+///
+/// The tag is [`TAG`](tagged::TAG), i.e. the registered CBOR tag for binary
+/// UUIDs, which applies since a ULID has the same 128-bit binary layout.
+///
+/// The functions of this module can be used with `minicbor-derive`:
+///
+/// ```rust,ignore
+/// #[derive(minicbor::Encode, minicbor::Decode)]
+/// struct Message {
+///     #[n(0)]
+///     #[cbor(with = "rusty_ulid::minicbor::tagged")]
+///     id: rusty_ulid::Ulid,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use minicbor::{Decoder, Encoder};
+/// use rusty_ulid::minicbor::tagged;
+/// use rusty_ulid::Ulid;
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+///
+/// let mut encoder = Encoder::new(Vec::new());
+/// tagged::encode(&ulid, &mut encoder, &mut ()).unwrap();
+/// let bytes = encoder.into_writer();
+/// assert_eq!(bytes[..3], [0xD8, 0x25, 0x50]);
+///
+/// let decoded = tagged::decode(&mut Decoder::new(&bytes), &mut ()).unwrap();
+/// assert_eq!(decoded, ulid);
+/// ```
+pub mod tagged {
+    use crate::Ulid;
+    use minicbor::data::Tag;
+    use minicbor::decode::{self, Decoder};
+    use minicbor::encode::{self, Encoder, Write};
+    use minicbor::{Decode, Encode};
+
+    /// The CBOR tag used for tagged ULIDs.
+    pub const TAG: u64 = 37;
+
+    /// Encodes a `Ulid` as a tagged CBOR byte string.
+    pub fn encode<C, W: Write>(
+        ulid: &Ulid,
+        e: &mut Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        let _ = e.tag(Tag::Unassigned(TAG))?;
+        ulid.encode(e, ctx)
+    }
+
+    /// Decodes a `Ulid` from a tagged CBOR byte string.
+    ///
+    /// Fails if the tag is missing or does not equal [`TAG`].
+    pub fn decode<C>(d: &mut Decoder<'_>, ctx: &mut C) -> Result<Ulid, decode::Error> {
+        let position = d.position();
+        match d.tag()? {
+            Tag::Unassigned(TAG) => Ulid::decode(d, ctx),
+            _ => Err(decode::Error::message("expected ULID tag").at(position)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minicbor_rejects_wrong_length() {
+        let mut encoder = Encoder::new(Vec::new());
+        let _ = encoder.bytes(&[0; 15]).unwrap();
+        let bytes = encoder.into_writer();
+
+        let result = minicbor::decode::<Ulid>(&bytes);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn minicbor_rejects_wrong_type() {
+        let bytes = minicbor::to_vec(42u32).unwrap();
+
+        let result = minicbor::decode::<Ulid>(&bytes);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn minicbor_tagged_rejects_untagged() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let bytes = minicbor::to_vec(ulid).unwrap();
+
+        let result = tagged::decode(&mut Decoder::new(&bytes), &mut ());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn minicbor_tagged_rejects_other_tag() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let mut encoder = Encoder::new(Vec::new());
+        let _ = encoder.tag(minicbor::data::Tag::Uri).unwrap();
+        ulid.encode(&mut encoder, &mut ()).unwrap();
+        let bytes = encoder.into_writer();
+
+        let result = tagged::decode(&mut Decoder::new(&bytes), &mut ());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! Endless, strictly increasing sequences of ULIDs, per iterator or per thread.
+
+use crate::{random_bits, unix_epoch_ms, Ulid};
+use std::cell::Cell;
+use std::time::Duration;
+
+// a fraction of a millisecond, so the next millisecond isn't overslept by much
+pub(crate) const WAIT_INTERVAL: Duration = Duration::from_micros(100);
+
+thread_local! {
+    static PREVIOUS_ULID: Cell<Option<Ulid>> = const { Cell::new(None) };
+}
+
+/// Iterator yielding an endless, strictly increasing stream of ULIDs.
+///
+/// Created by [`Ulid::iter_monotonic`]. Every ULID is created like
+/// [`Ulid::next_monotonic`] but, unlike that function, this iterator never
+/// yields a ULID that is smaller than or equal to its predecessor:
+///
+/// - if the random part overflows within the same millisecond, the next ULID
+///   borrows the following millisecond.
+/// - if the clock moves backwards, the previous ULID is incremented instead.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+///
+/// let ulids: Vec<Ulid> = Ulid::iter_monotonic().take(1000).collect();
+///
+/// assert_eq!(ulids.len(), 1000);
+/// assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MonotonicUlids {
+    previous: Option<Ulid>,
+}
+
+impl MonotonicUlids {
+    /// Creates a new iterator, starting with a newly generated ULID.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { previous: None }
+    }
+
+    /// Creates a new iterator whose first ULID is strictly larger than `previous_ulid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{MonotonicUlids, Ulid};
+    ///
+    /// let previous_ulid = Ulid::generate();
+    /// let mut ulids = MonotonicUlids::starting_after(previous_ulid);
+    ///
+    /// assert!(previous_ulid < ulids.next().unwrap());
+    /// ```
+    #[must_use]
+    pub const fn starting_after(previous_ulid: Ulid) -> Self {
+        Self {
+            previous: Some(previous_ulid),
+        }
+    }
+}
+
+impl Iterator for MonotonicUlids {
+    type Item = Ulid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = Ulid::next_strictly_increasing(self.previous, unix_epoch_ms(), random_bits);
+        self.previous = Some(result);
+
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl std::iter::FusedIterator for MonotonicUlids {}
+
+/// Returns the next ULID of the generator of the current thread.
+pub(crate) fn next_thread_local() -> Ulid {
+    PREVIOUS_ULID.with(|previous| {
+        let result = Ulid::next_strictly_increasing(previous.get(), unix_epoch_ms(), random_bits);
+        previous.set(Some(result));
+        result
+    })
+}
+
+/// Returns the next monotonic ULID for `timestamp` that is strictly larger than
+/// `previous_ulid`, or `None` if the random part of `previous_ulid` is exhausted
+/// and `timestamp` hasn't passed the timestamp of `previous_ulid` yet.
+pub(crate) fn next_without_borrowing(previous_ulid: Ulid, timestamp: u64) -> Option<Ulid> {
+    if previous_ulid.timestamp() < timestamp {
+        return Some(Ulid::from_timestamp_and_random_bits(
+            timestamp,
+            random_bits(),
+        ));
+    }
+
+    // same millisecond or clock moved backwards
+    let incremented = previous_ulid.increment();
+    (previous_ulid < incremented).then_some(incremented)
+}
+
+/// Returns the next ULID that is strictly larger than `previous_ulid`, sleeping
+/// until the next millisecond if the random part of `previous_ulid` is exhausted.
+pub(crate) fn next_waiting(previous_ulid: Ulid) -> Ulid {
+    loop {
+        if let Some(ulid) = next_without_borrowing(previous_ulid, unix_epoch_ms()) {
+            return ulid;
+        }
+        std::thread::sleep(WAIT_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow_borrows_next_millisecond() {
+        let previous_ulid = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
+
+        let ulid = Ulid::next_strictly_increasing(Some(previous_ulid), 1, random_bits);
+
+        assert_eq!(ulid.timestamp(), 2);
+    }
+
+    #[test]
+    fn clock_moving_backwards_increments() {
+        let previous_ulid = Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0000);
+
+        let ulid = Ulid::next_strictly_increasing(Some(previous_ulid), 1, random_bits);
+
+        assert_eq!(ulid, previous_ulid.increment());
+    }
+
+    #[test]
+    fn exhausted_random_part_is_not_borrowed() {
+        let previous_ulid = Ulid::from(0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF);
+
+        assert_eq!(next_without_borrowing(previous_ulid, 1), None);
+        assert_eq!(next_without_borrowing(previous_ulid, 0), None);
+        assert_eq!(
+            next_without_borrowing(previous_ulid, 2)
+                .unwrap()
+                .timestamp(),
+            2
+        );
+        assert_eq!(
+            next_without_borrowing(Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0000), 1),
+            Some(Ulid::from(0x0000_0000_0002_0000_0000_0000_0000_0001))
+        );
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[test]
+    fn waiting_continues_in_next_millisecond() {
+        let previous_ulid = Ulid::max_for_timestamp(unix_epoch_ms());
+
+        let ulid = next_waiting(previous_ulid);
+
+        assert!(previous_ulid < ulid);
+        assert!(previous_ulid.timestamp() < ulid.timestamp());
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[test]
+    fn thread_local_generators_are_independent() {
+        let first = next_thread_local();
+        let other_thread = std::thread::spawn(next_thread_local).join().unwrap();
+        let second = next_thread_local();
+
+        assert!(first < second);
+        assert_ne!(other_thread, second);
+    }
+
+    #[cfg(not(miri))] // libc::gettimeofday
+    #[test]
+    fn starting_after_continues_from_previous() {
+        let previous_ulid = Ulid::from(0xFFFF_FFFF_FFFE_0000_0000_0000_0000_0000);
+
+        let ulids: Vec<Ulid> = MonotonicUlids::starting_after(previous_ulid)
+            .take(2)
+            .collect();
+
+        assert_eq!(ulids[0], previous_ulid.increment());
+        assert_eq!(ulids[1], ulids[0].increment());
+    }
+}
@@ -0,0 +1,245 @@
+//! Strictly increasing ULIDs carrying the ID of the node that created them.
+
+use crate::{random_bits, unix_epoch_ms, EntropyLayout, Ulid, RANDOM_PART_MASK};
+
+/// Generator of strictly increasing ULIDs that carry a node ID.
+///
+/// The `node_bits` most significant bits of the random part, directly
+/// following the timestamp, hold the ID of the node, e.g. a machine or shard.
+/// As long as every node uses its own ID and a single generator, ULIDs created
+/// by different nodes can't collide, without any coordination between them.
+///
+/// The remaining `80 - node_bits` bits are random for the first ULID of a
+/// millisecond and incremented for every further ULID of the same millisecond,
+/// without ever carrying into the node ID. If they are exhausted, the next ULID
+/// borrows the following millisecond. If the clock moves backwards, the
+/// previous ULID is incremented instead.
+///
+/// [`with_layout`](Self::with_layout) additionally reserves bits for a sequence
+/// counter, see [`EntropyLayout`]. The node ID can be extracted from any ULID
+/// created by this generator with [`Ulid::node_id`] or [`EntropyLayout::node_id`].
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::NodeUlidGenerator;
+///
+/// let mut generator = NodeUlidGenerator::new(10, 0x2A5);
+///
+/// let first = generator.generate();
+/// let second = generator.generate();
+///
+/// assert!(first < second);
+/// assert_eq!(first.node_id(10), 0x2A5);
+/// assert_eq!(second.node_id(10), 0x2A5);
+/// ```
+// not Copy on purpose, copying a generator would allow creating duplicate ULIDs
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeUlidGenerator {
+    layout: EntropyLayout,
+    node_id: u32,
+    previous: Option<Ulid>,
+}
+
+impl NodeUlidGenerator {
+    /// Creates a new generator storing `node_id` in the `node_bits` most
+    /// significant bits of the random part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node_bits` is larger than 32 or if `node_id` doesn't fit
+    /// into `node_bits` bits.
+    #[must_use]
+    pub fn new(node_bits: u32, node_id: u32) -> Self {
+        Self::with_layout(EntropyLayout::new(node_bits, 0), node_id)
+    }
+
+    /// Creates a new generator following `layout`, storing `node_id` in the
+    /// node ID of every created ULID.
+    ///
+    /// The first ULID of a millisecond has the sequence counter 0, every further
+    /// ULID of the same millisecond the incremented counter and new random bits.
+    /// If the counter is exhausted, the next ULID borrows the following
+    /// millisecond. Without sequence counter, the generator behaves like one
+    /// created by [`new`](Self::new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::{EntropyLayout, NodeUlidGenerator};
+    ///
+    /// const LAYOUT: EntropyLayout = EntropyLayout::new(16, 16);
+    ///
+    /// let mut generator = NodeUlidGenerator::with_layout(LAYOUT, 42);
+    ///
+    /// let first = generator.generate();
+    /// let second = generator.generate();
+    ///
+    /// assert!(first < second);
+    /// assert_eq!(LAYOUT.node_id(&second), 42);
+    /// if first.timestamp() == second.timestamp() {
+    ///     assert_eq!(LAYOUT.sequence(&second), LAYOUT.sequence(&first) + 1);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node_id` doesn't fit into the node ID of `layout`.
+    #[must_use]
+    pub fn with_layout(layout: EntropyLayout, node_id: u32) -> Self {
+        // validates the node ID
+        let _ = Ulid::from(0).with_node_id(layout.node_bits(), node_id);
+
+        Self {
+            layout,
+            node_id,
+            previous: None,
+        }
+    }
+
+    /// Returns the layout of the random part of created ULIDs.
+    #[must_use]
+    pub fn layout(&self) -> EntropyLayout {
+        self.layout
+    }
+
+    /// Returns the number of bits reserved for the node ID.
+    #[must_use]
+    pub fn node_bits(&self) -> u32 {
+        self.layout.node_bits()
+    }
+
+    /// Returns the node ID stored in every created ULID.
+    #[must_use]
+    pub fn node_id(&self) -> u32 {
+        self.node_id
+    }
+
+    /// Returns the next ULID, strictly larger than the previous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    pub fn generate(&mut self) -> Ulid {
+        self.generate_for_timestamp(unix_epoch_ms())
+    }
+
+    fn generate_for_timestamp(&mut self, timestamp: u64) -> Ulid {
+        let ulid = match self.previous {
+            Some(previous_ulid) if timestamp <= previous_ulid.timestamp() => {
+                // same millisecond or clock moved backwards
+                self.next_in_millisecond(previous_ulid)
+            }
+            _ => self.create(timestamp),
+        };
+        self.previous = Some(ulid);
+
+        ulid
+    }
+
+    fn next_in_millisecond(&self, previous_ulid: Ulid) -> Ulid {
+        if self.layout.sequence_bits() == 0 {
+            let counter_mask = RANDOM_PART_MASK >> self.layout.node_bits();
+            let value = previous_ulid.to_u128();
+            if value & counter_mask != counter_mask {
+                return Ulid::from_u128(value + 1);
+            }
+        } else {
+            let sequence = self.layout.sequence(&previous_ulid);
+            if sequence < self.layout.max_sequence() {
+                return self
+                    .layout
+                    .with_sequence(self.create(previous_ulid.timestamp()), sequence + 1);
+            }
+        }
+
+        // counter exhausted, borrow the next millisecond
+        self.create(previous_ulid.timestamp() + 1)
+    }
+
+    fn create(&self, timestamp: u64) -> Ulid {
+        let ulid = Ulid::from_timestamp_and_random_bits(timestamp, random_bits())
+            .with_node_id(self.layout.node_bits(), self.node_id);
+        self.layout.with_sequence(ulid, 0)
+    }
+}
+
+impl Iterator for NodeUlidGenerator {
+    type Item = Ulid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.generate())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_millisecond_increments_below_node_id() {
+        let mut generator = NodeUlidGenerator::new(16, 0xF00F);
+
+        let first = generator.generate_for_timestamp(1);
+        let second = generator.generate_for_timestamp(1);
+        let third = generator.generate_for_timestamp(0);
+
+        assert_eq!(second, first.increment());
+        assert_eq!(third, second.increment());
+        assert_eq!(third.node_id(16), 0xF00F);
+    }
+
+    #[test]
+    fn exhausted_random_bits_borrow_next_millisecond() {
+        let mut generator = NodeUlidGenerator::new(16, 0xF00F);
+        generator.previous = Some(Ulid::from(0x0000_0000_0001_F00F_FFFF_FFFF_FFFF_FFFF));
+
+        let ulid = generator.generate_for_timestamp(1);
+
+        assert_eq!(ulid.timestamp(), 2);
+        assert_eq!(ulid.node_id(16), 0xF00F);
+    }
+
+    #[test]
+    fn nodes_never_collide() {
+        let mut first = NodeUlidGenerator::new(1, 0);
+        let mut second = NodeUlidGenerator::new(1, 1);
+
+        for _ in 0..1000 {
+            let ulid = first.generate_for_timestamp(1);
+            let other = second.generate_for_timestamp(1);
+
+            assert_eq!(ulid.node_id(1), 0);
+            assert_eq!(other.node_id(1), 1);
+            assert!(ulid < other);
+        }
+    }
+
+    #[test]
+    fn sequence_counts_ulids_of_millisecond() {
+        let layout = EntropyLayout::new(8, 2);
+        let mut generator = NodeUlidGenerator::with_layout(layout, 0xA5);
+
+        let ulids: Vec<Ulid> = (0..5)
+            .map(|_| generator.generate_for_timestamp(1))
+            .collect();
+
+        assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ulids.iter().all(|ulid| layout.node_id(ulid) == 0xA5));
+        let sequences: Vec<u32> = ulids.iter().map(|ulid| layout.sequence(ulid)).collect();
+        assert_eq!(sequences, [0, 1, 2, 3, 0]);
+        assert_eq!(ulids[3].timestamp(), 1);
+        assert_eq!(ulids[4].timestamp(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "node ID 1024 doesn't fit into 10 bits")]
+    fn node_id_must_fit() {
+        let _ = NodeUlidGenerator::new(10, 1024);
+    }
+}
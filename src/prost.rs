@@ -0,0 +1,147 @@
+//! Protobuf support for [prost](https://docs.rs/prost).
+//!
+//! # Enabling
+//!
+//! This module is only available when the `prost` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["prost"]
+//! ```
+//!
+//! # Usage
+//!
+//! A ULID is best transferred as its 16 bytes in big-endian byte order instead
+//! of the 26 character string. A `bytes` field converts from and to a `Ulid`
+//! with `From<Ulid>` for `Bytes` as well as `TryFrom<Bytes>` and
+//! `TryFrom<&[u8]>` for `Ulid`:
+//!
+//! ```rust
+//! use prost::bytes::Bytes;
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let bytes = Bytes::from(ulid);
+//! assert_eq!(bytes.len(), 16);
+//!
+//! assert_eq!(Ulid::try_from(&bytes[..]), Ok(ulid));
+//! ```
+//!
+//! # Message
+//!
+//! Schemas that use a message for ULIDs,
+//!
+//! ```protobuf
+//! message Ulid {
+//!   bytes value = 1;
+//! }
+//! ```
+//!
+//! can map it to [`UlidMessage`] with `prost_build::Config::extern_path`, e.g.
+//! `config.extern_path(".example.Ulid", "::rusty_ulid::prost::UlidMessage")`,
+//! instead of generating a type of their own:
+//!
+//! ```rust
+//! use prost::Message;
+//! use rusty_ulid::prost::UlidMessage;
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let encoded = UlidMessage::from(ulid).encode_to_vec();
+//! assert_eq!(encoded.len(), 18);
+//!
+//! let decoded = UlidMessage::decode(&encoded[..]).unwrap();
+//! assert_eq!(Ulid::try_from(decoded), Ok(ulid));
+//! ```
+
+use crate::{DecodingError, Ulid};
+use prost::bytes::Bytes;
+
+/// The protobuf message `message Ulid { bytes value = 1; }`.
+///
+/// `value` contains the 16 bytes of the ULID in big-endian byte order.
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct UlidMessage {
+    /// The 16 bytes of the ULID.
+    #[prost(bytes = "vec", tag = "1")]
+    pub value: Vec<u8>,
+}
+
+impl From<Ulid> for UlidMessage {
+    fn from(ulid: Ulid) -> Self {
+        Self {
+            value: ulid.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<&UlidMessage> for Ulid {
+    type Error = DecodingError;
+
+    fn try_from(message: &UlidMessage) -> Result<Self, Self::Error> {
+        Self::try_from(&message.value[..])
+    }
+}
+
+impl TryFrom<UlidMessage> for Ulid {
+    type Error = DecodingError;
+
+    fn try_from(message: UlidMessage) -> Result<Self, Self::Error> {
+        Self::try_from(&message)
+    }
+}
+
+impl From<Ulid> for Bytes {
+    fn from(ulid: Ulid) -> Self {
+        Bytes::copy_from_slice(ulid.as_bytes())
+    }
+}
+
+impl TryFrom<Bytes> for Ulid {
+    type Error = DecodingError;
+
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+        Self::try_from(&bytes[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn message_matches_wire_format() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        let encoded = UlidMessage::from(ulid).encode_to_vec();
+
+        // field 1, wire type 2 (length-delimited), 16 bytes
+        assert_eq!(encoded[..2], [0x0A, 0x10]);
+        assert_eq!(encoded[2..], ulid.to_bytes());
+    }
+
+    #[test]
+    fn invalid_lengths_are_rejected() {
+        let message = UlidMessage::default();
+
+        assert_eq!(
+            Ulid::try_from(message),
+            Err(DecodingError::InvalidLength {
+                expected: 16,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            Ulid::try_from(Bytes::from_static(&[0; 17])),
+            Err(DecodingError::InvalidLength {
+                expected: 16,
+                actual: 17
+            })
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! ULID generation using [rand](https://docs.rs/rand) 0.9.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `rand09` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! default-features = false
+//! features = ["rand09", "time", "serde"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid::generate()` and the other functions using the current time work with
+//! either `rand` (i.e. rand 0.8) or `rand09` enabled. If only `rand09` is
+//! enabled, rand 0.8 is no longer part of the dependency tree.
+//!
+//! The functions of this module are the rand 0.9 counterparts of the
+//! `Ulid::*_with_rng` functions, which accept a rand 0.8 `Rng`:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let mut rng = rand09::rng();
+//!
+//! let ulid = rusty_ulid::rand09::from_timestamp_with_rng(0, &mut rng);
+//! assert_eq!(ulid.timestamp(), 0);
+//!
+//! let next = rusty_ulid::rand09::next_monotonic_from_timestamp_with_rng(ulid, 0, &mut rng);
+//! assert!(ulid < next);
+//! ```
+
+use crate::Ulid;
+use rand09::Rng;
+
+/// Creates a new ULID with the given `timestamp` obtaining randomness from `rng`.
+///
+/// See [`Ulid::from_timestamp_with_rng`].
+///
+/// # Panics
+///
+/// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+pub fn from_timestamp_with_rng<R>(timestamp: u64, rng: &mut R) -> Ulid
+where
+    R: Rng + ?Sized,
+{
+    Ulid::from_timestamp_and_random_bits(timestamp, (rng.random(), rng.random()))
+}
+
+/// Creates the next monotonic ULID with the given `previous_ulid`, `timestamp`
+/// obtaining randomness from `rng`.
+///
+/// See [`Ulid::next_monotonic_from_timestamp_with_rng`].
+///
+/// # Panics
+///
+/// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+pub fn next_monotonic_from_timestamp_with_rng<R>(
+    previous_ulid: Ulid,
+    timestamp: u64,
+    rng: &mut R,
+) -> Ulid
+where
+    R: Rng + ?Sized,
+{
+    next_monotonic_from_timestamp_with_rng_and_postprocessor(
+        Some(previous_ulid),
+        timestamp,
+        rng,
+        None,
+    )
+}
+
+/// Creates the next monotonic ULID with the given `previous_ulid`, `timestamp`
+/// obtaining randomness from `rng`, transforming newly created ULIDs with
+/// `postprocessor`.
+///
+/// See [`Ulid::next_monotonic_from_timestamp_with_rng_and_postprocessor`].
+///
+/// # Panics
+///
+/// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+pub fn next_monotonic_from_timestamp_with_rng_and_postprocessor<R>(
+    previous_ulid: Option<Ulid>,
+    timestamp: u64,
+    rng: &mut R,
+    postprocessor: Option<&dyn Fn(Ulid) -> Ulid>,
+) -> Ulid
+where
+    R: Rng + ?Sized,
+{
+    Ulid::next_monotonic_from_timestamp_and_random_bits(
+        previous_ulid,
+        timestamp,
+        || (rng.random(), rng.random()),
+        postprocessor,
+    )
+}
+
+/// Creates the next strictly monotonic ULID with the given `previous_ulid`,
+/// `timestamp` obtaining randomness from `rng`.
+///
+/// See [`Ulid::next_strictly_monotonic_from_timestamp_with_rng`].
+///
+/// # Panics
+///
+/// Panics if `timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+pub fn next_strictly_monotonic_from_timestamp_with_rng<R>(
+    previous_ulid: Ulid,
+    timestamp: u64,
+    rng: &mut R,
+) -> Option<Ulid>
+where
+    R: Rng + ?Sized,
+{
+    let result = next_monotonic_from_timestamp_with_rng(previous_ulid, timestamp, rng);
+
+    if previous_ulid < result {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand09::RngCore;
+
+    /// Rng returning `value` for every call.
+    struct ConstRng(u64);
+
+    impl RngCore for ConstRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand09::rand_core::impls::fill_bytes_via_next(self, dest);
+        }
+    }
+
+    #[test]
+    fn from_timestamp_with_mock_rng() {
+        let mut rng = ConstRng(0xF00F);
+
+        let ulid = from_timestamp_with_rng(1, &mut rng);
+
+        assert_eq!(ulid, Ulid::from(0x0000_0000_0001_F00F_0000_0000_0000_F00F));
+    }
+
+    #[test]
+    fn next_monotonic_increments_within_millisecond() {
+        let mut rng = ConstRng(0);
+
+        let ulid = next_monotonic_from_timestamp_with_rng(Ulid::from(0), 0, &mut rng);
+
+        assert_eq!(ulid, Ulid::from(1));
+    }
+
+    #[test]
+    fn next_strictly_monotonic_overflow() {
+        let mut rng = ConstRng(0);
+        let previous_ulid = Ulid::from(0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF);
+
+        let ulid = next_strictly_monotonic_from_timestamp_with_rng(previous_ulid, 0, &mut rng);
+
+        assert_eq!(ulid, None);
+    }
+}
@@ -0,0 +1,81 @@
+//! Formatting of ULIDs that hides their random part.
+
+use crate::Ulid;
+use core::fmt;
+
+/// Wrapper whose `Display` and `Debug` only show the timestamp of a ULID.
+///
+/// The first 10 characters, i.e. the timestamp, are kept and the 16 characters
+/// of the random part are replaced by `…***`. This limits what leaks if a
+/// ULID used as a sensitive identifier ends up in a log message, while the
+/// time of its creation can still be correlated.
+///
+/// `T` is usually a [`Ulid`] or a [`TypedUlid`](crate::TypedUlid).
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::{Redacted, Ulid};
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+///
+/// assert_eq!(Redacted(ulid).to_string(), "01CAH7NXGR…***");
+/// assert_eq!(format!("{:?}", ulid.redacted()), "01CAH7NXGR…***");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Redacted<T = Ulid>(pub T);
+
+impl<T: Copy + Into<Ulid>> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.into().encode_to_str(&mut [0; 26])[..10])?;
+        f.write_str("…***")
+    }
+}
+
+impl<T: Copy + Into<Ulid>> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T> From<T> for Redacted<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TypedUlid;
+
+    #[test]
+    fn only_timestamp_is_shown() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        assert_eq!(Redacted(ulid).to_string(), "01CAH7NXGR…***");
+        assert_eq!(
+            Redacted(Ulid::from(u128::MAX)).to_string(),
+            "7ZZZZZZZZZ…***"
+        );
+        assert_eq!(
+            Redacted(TypedUlid::<()>::from_ulid(ulid)).to_string(),
+            "01CAH7NXGR…***"
+        );
+    }
+
+    #[test]
+    fn debug_of_containing_struct_is_redacted() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Session {
+            token: Redacted,
+        }
+
+        let session = Session {
+            token: Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67).redacted(),
+        };
+
+        assert_eq!(format!("{session:?}"), "Session { token: 01CAH7NXGR…*** }");
+    }
+}
@@ -0,0 +1,49 @@
+//! [rkyv](https://rkyv.org) zero-copy serialization support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `rkyv` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["rkyv"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Archive`](rkyv::Archive), [`Serialize`](rkyv::Serialize) and
+//! [`Deserialize`](rkyv::Deserialize). The archived type [`ArchivedUlid`] can be
+//! compared to a `Ulid` and converted into one without deserialization:
+//!
+//! ```rust
+//! use rusty_ulid::{ArchivedUlid, Ulid};
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let bytes = rkyv::to_bytes::<_, 16>(&ulid).unwrap();
+//! let archived = unsafe { rkyv::archived_root::<Ulid>(&bytes[..]) };
+//!
+//! assert_eq!(archived, &ulid);
+//! assert_eq!(Ulid::from(archived), ulid);
+//! assert_eq!(archived.timestamp(), ulid.timestamp());
+//! ```
+
+use crate::{ArchivedUlid, Ulid};
+
+impl ArchivedUlid {
+    /// Returns the timestamp of this archived ULID, see [`Ulid::timestamp`].
+    #[must_use]
+    pub fn timestamp(&self) -> u64 {
+        Ulid::from(self).timestamp()
+    }
+}
+
+impl From<&ArchivedUlid> for Ulid {
+    fn from(archived: &ArchivedUlid) -> Self {
+        Self {
+            bytes: archived.bytes,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! [rusqlite](https://docs.rs/rusqlite) column type support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `rusqlite` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["rusqlite"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`ToSql`] and [`FromSql`]. It is bound as `TEXT` containing
+//! the canonical ULID string and can be read back from either a `TEXT` column or
+//! a 16-byte `BLOB` column using the same big-endian byte order as
+//! `<[u8; 16]>::from(ulid)`:
+//!
+//! ```rust
+//! use rusqlite::Connection;
+//! use rusty_ulid::Ulid;
+//!
+//! let connection = Connection::open_in_memory().unwrap();
+//! connection
+//!     .execute("CREATE TABLE events (id TEXT PRIMARY KEY)", ())
+//!     .unwrap();
+//!
+//! let id = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//! connection
+//!     .execute("INSERT INTO events (id) VALUES (?1)", [id])
+//!     .unwrap();
+//!
+//! let fetched: Ulid = connection
+//!     .query_row("SELECT id FROM events", [], |row| row.get(0))
+//!     .unwrap();
+//! assert_eq!(fetched, id);
+//! ```
+
+use crate::Ulid;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+impl ToSql for Ulid {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for Ulid {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(text) => std::str::from_utf8(text)
+                .map_err(|error| FromSqlError::Other(Box::new(error)))?
+                .parse()
+                .map_err(|error| FromSqlError::Other(Box::new(error))),
+            ValueRef::Blob(blob) => <[u8; 16]>::try_from(blob).map(Self::from).map_err(|_| {
+                FromSqlError::InvalidBlobSize {
+                    expected_size: 16,
+                    blob_size: blob.len(),
+                }
+            }),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn select(sql: &str) -> rusqlite::Result<Ulid> {
+        let connection = Connection::open_in_memory()?;
+        connection.query_row(sql, [], |row| row.get(0))
+    }
+
+    #[test]
+    fn from_blob() {
+        assert_eq!(
+            select("SELECT X'0162A27AF6186CAAE4AC35F1ED787B67'"),
+            Ok(Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67))
+        );
+    }
+
+    #[test]
+    fn from_invalid_values() {
+        assert!(select("SELECT X'0162A27AF6186CAAE4AC35F1ED787B'").is_err());
+        assert!(select("SELECT '01CAH7NXGRDJNE9B1NY7PQGYV'").is_err());
+        assert!(select("SELECT 42").is_err());
+        assert!(select("SELECT NULL").is_err());
+    }
+
+    #[test]
+    fn to_sql_binds_text() {
+        let connection = Connection::open_in_memory().unwrap();
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        let column_type: String = connection
+            .query_row("SELECT typeof(?1)", [ulid], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(column_type, "text");
+    }
+}
@@ -0,0 +1,102 @@
+//! SCALE codec support for [parity-scale-codec](https://docs.rs/parity-scale-codec).
+//!
+//! # Enabling
+//!
+//! This module is only available when the `parity-scale-codec` feature is
+//! enabled. Enable it in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["parity-scale-codec"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements `Encode`, `Decode`, `MaxEncodedLen` and
+//! `ConstEncodedLen`, so it can be used in the storage and extrinsics of
+//! Substrate-based chains. It is encoded like a `[u8; 16]`, i.e. as the 16
+//! bytes in big-endian byte order without length prefix:
+//!
+//! ```rust
+//! use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let encoded = ulid.encode();
+//! assert_eq!(encoded, ulid.to_bytes());
+//! assert_eq!(Ulid::max_encoded_len(), 16);
+//!
+//! assert_eq!(Ulid::decode(&mut &encoded[..]), Ok(ulid));
+//! ```
+
+use crate::Ulid;
+use parity_scale_codec::{
+    ConstEncodedLen, Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input,
+    MaxEncodedLen, Output,
+};
+
+impl Encode for Ulid {
+    fn size_hint(&self) -> usize {
+        16
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.as_bytes());
+    }
+
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(self.as_bytes())
+    }
+
+    fn encoded_size(&self) -> usize {
+        16
+    }
+}
+
+impl EncodeLike for Ulid {}
+
+impl Decode for Ulid {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = [0; 16];
+        input.read(&mut bytes)?;
+        Ok(Self::from(bytes))
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(16)
+    }
+}
+
+impl DecodeWithMemTracking for Ulid {}
+
+impl MaxEncodedLen for Ulid {
+    fn max_encoded_len() -> usize {
+        16
+    }
+}
+
+impl ConstEncodedLen for Ulid {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding_matches_byte_array() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        assert_eq!(ulid.encode(), ulid.to_bytes().encode());
+        assert_eq!(ulid.encoded_size(), 16);
+        assert_eq!((ulid, 7_u8).encode().len(), 17);
+    }
+
+    #[test]
+    fn truncated_input_is_rejected() {
+        let encoded = Ulid::from(u128::MAX).encode();
+
+        assert!(Ulid::decode(&mut &encoded[..15]).is_err());
+        assert_eq!(Ulid::decode(&mut &encoded[..]), Ok(Ulid::from(u128::MAX)));
+    }
+}
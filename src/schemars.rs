@@ -23,6 +23,12 @@ impl JsonSchema for Ulid {
             })),
             instance_type: Some(InstanceType::String.into()),
             format: Some("ulid".to_string()),
+            // for validators ignoring the unknown format
+            string: Some(Box::new(StringValidation {
+                max_length: Some(26),
+                min_length: Some(26),
+                pattern: Some("^[0-7][0-9A-HJKMNP-TV-Z]{25}$".to_string()),
+            })),
             ..Default::default()
         }
         .into()
@@ -0,0 +1,168 @@
+//! Deterministic ULID generation for reproducible test data.
+//!
+//! [`SeededUlidGenerator`] creates the same sequence of ULIDs for the same seed
+//! and start timestamp, on every platform and independent of enabled features.
+//! This makes it suitable for snapshot tests and example datasets that need
+//! stable IDs across runs.
+//!
+//! **Do not use it for real IDs.** The random part is derived from the seed and
+//! is therefore trivially predictable.
+
+use crate::Ulid;
+
+/// Generator of a reproducible, strictly monotonic sequence of ULIDs.
+///
+/// The first ULID has the timestamp `start_timestamp`, every following ULID
+/// has a timestamp one millisecond later than its predecessor. The 80 random
+/// bits are created by a [SplitMix64](https://prng.di.unimi.it/splitmix64.c)
+/// generator initialized with `seed`.
+///
+/// The sequence created for a given seed and start timestamp is part of the
+/// public API and won't change in a semver compatible release.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::SeededUlidGenerator;
+///
+/// let mut generator = SeededUlidGenerator::new(42, 1_523_144_390_168);
+///
+/// let first = generator.generate();
+/// let second = generator.generate();
+///
+/// assert_eq!(first.timestamp(), 1_523_144_390_168);
+/// assert_eq!(second.timestamp(), 1_523_144_390_169);
+/// assert!(first < second);
+///
+/// // the same seed and start timestamp result in the same ULIDs
+/// let mut generator = SeededUlidGenerator::new(42, 1_523_144_390_168);
+/// assert_eq!(generator.generate(), first);
+/// assert_eq!(generator.generate(), second);
+/// ```
+///
+/// The generator is also an `Iterator`, ending once the maximum timestamp
+/// has been used.
+///
+/// ```
+/// use rusty_ulid::SeededUlidGenerator;
+///
+/// let ulids: Vec<_> = SeededUlidGenerator::new(42, 0).take(3).collect();
+///
+/// assert_eq!(ulids.len(), 3);
+/// assert_eq!(ulids[2].timestamp(), 2);
+/// ```
+// not Copy on purpose, copying a generator would silently repeat the sequence
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeededUlidGenerator {
+    state: u64,
+    timestamp: u64,
+}
+
+impl SeededUlidGenerator {
+    /// Creates a new generator with the given `seed` that starts at `start_timestamp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_timestamp` is larger than `0xFFFF_FFFF_FFFF`.
+    #[must_use]
+    pub fn new(seed: u64, start_timestamp: u64) -> Self {
+        if (start_timestamp & 0xFFFF_0000_0000_0000) != 0 {
+            panic!("ULID does not support timestamps after +10889-08-02T05:31:50.655Z");
+        }
+
+        Self {
+            state: seed,
+            timestamp: start_timestamp,
+        }
+    }
+
+    /// Returns the next ULID of the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp of the next ULID would be larger than `0xFFFF_FFFF_FFFF`.
+    pub fn generate(&mut self) -> Ulid {
+        match self.next() {
+            Some(ulid) => ulid,
+            None => panic!("ULID does not support timestamps after +10889-08-02T05:31:50.655Z"),
+        }
+    }
+
+    fn next_random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Iterator for SeededUlidGenerator {
+    type Item = Ulid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if (self.timestamp & 0xFFFF_0000_0000_0000) != 0 {
+            return None;
+        }
+
+        let random = ((self.next_random() >> 48) as u16, self.next_random());
+        let ulid = Ulid::from_timestamp_and_random_bits(self.timestamp, random);
+        self.timestamp += 1;
+
+        Some(ulid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_is_stable() {
+        let mut generator = SeededUlidGenerator::new(42, 1_523_144_390_168);
+
+        assert_eq!(
+            generator.generate().to_string(),
+            "01CAH7NXGRQQBJHVZ36ES6DW83"
+        );
+        assert_eq!(
+            generator.generate().to_string(),
+            "01CAH7NXGS8X95G771ZW74NRWM"
+        );
+        assert_eq!(
+            generator.generate().to_string(),
+            "01CAH7NXGT16YDWH1HZ8Y81PR6"
+        );
+    }
+
+    #[test]
+    fn different_seeds_create_different_sequences() {
+        let first = SeededUlidGenerator::new(1, 0).generate();
+        let second = SeededUlidGenerator::new(2, 0).generate();
+
+        assert_eq!(first.timestamp(), second.timestamp());
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn iterator_ends_after_max_timestamp() {
+        let mut generator = SeededUlidGenerator::new(0, 0xFFFF_FFFF_FFFF);
+
+        assert_eq!(
+            generator.next().map(|ulid| ulid.timestamp()),
+            Some(0xFFFF_FFFF_FFFF)
+        );
+        assert_eq!(generator.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "ULID does not support timestamps after +10889-08-02T05:31:50.655Z")]
+    fn generate_panics_after_max_timestamp() {
+        let mut generator = SeededUlidGenerator::new(0, 0xFFFF_FFFF_FFFF);
+
+        let _ = generator.generate();
+        let _ = generator.generate();
+    }
+}
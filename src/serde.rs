@@ -0,0 +1,664 @@
+//! Helper modules for alternative [serde](https://serde.rs) representations of `Ulid`.
+//!
+//! These modules are meant to be used with `#[serde(with = "...")]` on fields of type `Ulid`.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `serde` feature is enabled.
+
+/// Serializes a `Ulid` as a canonical, hyphenated UUID string.
+///
+/// Deserialization accepts either a UUID string or a ULID string.
+///
+/// The 128 bits of the ULID are used as they are, without any conversion.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "rusty_ulid::serde::ulid_as_uuid")]
+///     id: Ulid,
+/// }
+///
+/// let record = Record {
+///     id: Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67),
+/// };
+///
+/// let json = serde_json::to_string(&record)?;
+/// assert_eq!(json, r#"{"id":"0162a27a-f618-6caa-e4ac-35f1ed787b67"}"#);
+///
+/// let parsed: Record = serde_json::from_str(&json)?;
+/// assert_eq!(parsed.id, record.id);
+///
+/// let parsed: Record = serde_json::from_str(r#"{"id":"01CAH7NXGRDJNE9B1NY7PQGYV7"}"#)?;
+/// assert_eq!(parsed.id, record.id);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ulid_as_uuid {
+    use crate::Ulid;
+    use serde::{de, Deserializer, Serializer};
+    use std::fmt;
+
+    /// Serializes `ulid` as a UUID string.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `serializer`.
+    pub fn serialize<S: Serializer>(ulid: &Ulid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&ulid.to_uuid_string())
+    }
+
+    /// Deserializes a `Ulid` from either a UUID string or a ULID string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is neither a valid UUID nor a valid ULID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ulid, D::Error> {
+        struct UuidOrUlidStringVisitor;
+
+        impl de::Visitor<'_> for UuidOrUlidStringVisitor {
+            type Value = Ulid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a UUID or ULID string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
+                if value.len() == 36 {
+                    Ulid::from_uuid_str(value).map_err(E::custom)
+                } else {
+                    value.parse::<Ulid>().map_err(E::custom)
+                }
+            }
+        }
+
+        deserializer.deserialize_str(UuidOrUlidStringVisitor)
+    }
+}
+
+/// Serializes a `Ulid` as a `u128`.
+///
+/// This is useful for binary formats and columnar stores preferring a numeric
+/// encoding over a 26 character string.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "rusty_ulid::serde::ulid_as_u128")]
+///     id: Ulid,
+/// }
+///
+/// let record = Record {
+///     id: Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67),
+/// };
+///
+/// let json = serde_json::to_string(&record)?;
+/// assert_eq!(json, r#"{"id":1841368580275787095083546476239420263}"#);
+///
+/// let parsed: Record = serde_json::from_str(&json)?;
+/// assert_eq!(parsed.id, record.id);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ulid_as_u128 {
+    use crate::Ulid;
+    use serde::{de, Deserializer, Serializer};
+    use std::fmt;
+
+    /// Serializes `ulid` as a `u128`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `serializer`.
+    pub fn serialize<S: Serializer>(ulid: &Ulid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128((*ulid).into())
+    }
+
+    /// Deserializes a `Ulid` from an unsigned integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not an unsigned integer.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ulid, D::Error> {
+        struct UlidU128Visitor;
+
+        impl de::Visitor<'_> for UlidU128Visitor {
+            type Value = Ulid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a ULID as u128")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Ulid, E> {
+                Ok(u128::from(value).into())
+            }
+
+            fn visit_u128<E: de::Error>(self, value: u128) -> Result<Ulid, E> {
+                Ok(value.into())
+            }
+        }
+
+        deserializer.deserialize_u128(UlidU128Visitor)
+    }
+}
+
+/// Deserializes a `Ulid` from a ULID string, 16 bytes or a sequence of 16 bytes,
+/// regardless of whether the format is human readable.
+///
+/// The default `Deserialize` implementation of `Ulid` expects a string in human readable
+/// formats and bytes otherwise. This module uses `deserialize_any` instead, so it only works
+/// with self-describing formats. Serialization uses the default `Serialize` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "rusty_ulid::serde::flexible")]
+///     id: Ulid,
+/// }
+///
+/// let expected = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+///
+/// let parsed: Record = serde_json::from_str(r#"{"id":"01CAH7NXGRDJNE9B1NY7PQGYV7"}"#)?;
+/// assert_eq!(parsed.id, expected);
+///
+/// let parsed: Record = serde_json::from_str(
+///     r#"{"id":[1,98,162,122,246,24,108,170,228,172,53,241,237,120,123,103]}"#,
+/// )?;
+/// assert_eq!(parsed.id, expected);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod flexible {
+    use crate::Ulid;
+    use serde::{de, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    /// Serializes `ulid` using its default `Serialize` implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `serializer`.
+    pub fn serialize<S: Serializer>(ulid: &Ulid, serializer: S) -> Result<S::Ok, S::Error> {
+        ulid.serialize(serializer)
+    }
+
+    /// Deserializes a `Ulid` from a ULID string, 16 bytes or a sequence of 16 bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is none of the above.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ulid, D::Error> {
+        struct UlidFlexibleVisitor;
+
+        impl<'de> de::Visitor<'de> for UlidFlexibleVisitor {
+            type Value = Ulid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a ULID string or 16 ULID bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
+                value.parse::<Ulid>().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Ulid, E> {
+                Ulid::try_from(value).map_err(E::custom)
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Ulid, A::Error> {
+                let mut bytes = [0u8; 16];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(17, &self));
+                }
+
+                Ok(Ulid::from(bytes))
+            }
+        }
+
+        deserializer.deserialize_any(UlidFlexibleVisitor)
+    }
+}
+
+/// Deserializes a `Ulid` only from a ULID string in canonical form in human readable formats.
+///
+/// The default `Deserialize` implementation of `Ulid` accepts lowercase letters and the
+/// aliases `i`, `l` and `o`. This module rejects them like [`Ulid::from_str_strict`], so
+/// APIs can refuse sloppy identifiers at the boundary. Formats that aren't human readable
+/// use 16 bytes as usual. Serialization uses the default `Serialize` implementation.
+///
+/// [`Ulid::from_str_strict`]: crate::Ulid::from_str_strict
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "rusty_ulid::serde::strict")]
+///     id: Ulid,
+/// }
+///
+/// let parsed: Record = serde_json::from_str(r#"{"id":"01CAH7NXGRDJNE9B1NY7PQGYV7"}"#)?;
+/// assert_eq!(parsed.id, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+///
+/// let error = serde_json::from_str::<Record>(r#"{"id":"01cah7nxgrdjne9b1ny7pqgyv7"}"#);
+/// assert!(error.is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod strict {
+    use crate::Ulid;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    /// Serializes `ulid` using its default `Serialize` implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `serializer`.
+    pub fn serialize<S: Serializer>(ulid: &Ulid, serializer: S) -> Result<S::Ok, S::Error> {
+        ulid.serialize(serializer)
+    }
+
+    /// Deserializes a `Ulid` from a canonical ULID string, or from 16 bytes if the
+    /// format isn't human readable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a ULID in canonical form.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ulid, D::Error> {
+        struct CanonicalUlidStringVisitor;
+
+        impl de::Visitor<'_> for CanonicalUlidStringVisitor {
+            type Value = Ulid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a canonical ULID string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Ulid, E> {
+                Ulid::from_str_strict(value).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(CanonicalUlidStringVisitor)
+        } else {
+            Ulid::deserialize(deserializer)
+        }
+    }
+}
+
+/// Serializes a map with `Ulid` keys, e.g. `HashMap<Ulid, V>` or `BTreeMap<Ulid, V>`,
+/// using ULID strings as keys regardless of whether the format is human readable.
+///
+/// The default `Serialize` implementation of `Ulid` uses bytes in formats that aren't
+/// human readable, which many formats either don't support as map keys or represent
+/// in surprising ways. This module makes sure that ULID-keyed maps round-trip in all formats.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::Ulid;
+/// use serde::{Deserialize, Serialize};
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Index {
+///     #[serde(with = "rusty_ulid::serde::ulid_keys_as_str")]
+///     names: BTreeMap<Ulid, String>,
+/// }
+///
+/// let mut names = BTreeMap::new();
+/// names.insert(Ulid::from(0), "zero".to_string());
+/// let index = Index { names };
+///
+/// let json = serde_json::to_string(&index)?;
+/// assert_eq!(json, r#"{"names":{"00000000000000000000000000":"zero"}}"#);
+///
+/// let parsed: Index = serde_json::from_str(&json)?;
+/// assert_eq!(parsed.names, index.names);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod ulid_keys_as_str {
+    use crate::Ulid;
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    /// Serializes `map` using ULID strings as keys.
+    ///
+    /// The number of entries is passed to the serializer up front, as required
+    /// by formats like bincode and postcard.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `serializer`.
+    pub fn serialize<'a, S, M, V>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        &'a M: IntoIterator<Item = (&'a Ulid, &'a V)>,
+        <&'a M as IntoIterator>::IntoIter: ExactSizeIterator,
+        V: Serialize + 'a,
+    {
+        let entries = map.into_iter();
+        let mut map_serializer = serializer.serialize_map(Some(entries.len()))?;
+        for (key, value) in entries {
+            ser::SerializeMap::serialize_entry(&mut map_serializer, &key.to_string(), value)?;
+        }
+        ser::SerializeMap::end(map_serializer)
+    }
+
+    /// Deserializes a map with ULID string keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a key is not a valid ULID string or a value can't be deserialized.
+    pub fn deserialize<'de, D, M, V>(deserializer: D) -> Result<M, D::Error>
+    where
+        D: Deserializer<'de>,
+        M: Default + Extend<(Ulid, V)>,
+        V: Deserialize<'de>,
+    {
+        struct UlidKeyedMapVisitor<M, V> {
+            marker: PhantomData<(M, V)>,
+        }
+
+        impl<'de, M, V> de::Visitor<'de> for UlidKeyedMapVisitor<M, V>
+        where
+            M: Default + Extend<(Ulid, V)>,
+            V: Deserialize<'de>,
+        {
+            type Value = M;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a map with ULID string keys")
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut access: A) -> Result<M, A::Error> {
+                let mut map = M::default();
+                while let Some((StrKey(key), value)) = access.next_entry::<StrKey, V>()? {
+                    map.extend(Some((key, value)));
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(UlidKeyedMapVisitor {
+            marker: PhantomData,
+        })
+    }
+
+    struct StrKey(Ulid);
+
+    impl<'de> Deserialize<'de> for StrKey {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct UlidStrKeyVisitor;
+
+            impl de::Visitor<'_> for UlidStrKeyVisitor {
+                type Value = StrKey;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(formatter, "a ULID string")
+                }
+
+                fn visit_str<E: de::Error>(self, value: &str) -> Result<StrKey, E> {
+                    value.parse::<Ulid>().map(StrKey).map_err(E::custom)
+                }
+            }
+
+            deserializer.deserialize_str(UlidStrKeyVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ulid;
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token,
+    };
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    struct AsUuid(#[serde(with = "ulid_as_uuid")] Ulid);
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    struct AsU128(#[serde(with = "ulid_as_u128")] Ulid);
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    struct Flexible(#[serde(with = "flexible")] Ulid);
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    struct Strict(#[serde(with = "strict")] Ulid);
+
+    #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[serde(transparent)]
+    struct KeysAsStr(#[serde(with = "ulid_keys_as_str")] std::collections::BTreeMap<Ulid, u8>);
+
+    #[test]
+    fn ulid_as_uuid() {
+        let value = AsUuid(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F));
+
+        assert_ser_tokens(
+            &value,
+            &[Token::Str("11223344-5566-7788-99aa-bbccddeef00f")],
+        );
+        assert_de_tokens(
+            &value,
+            &[Token::Str("11223344-5566-7788-99aa-bbccddeef00f")],
+        );
+        assert_de_tokens(&value, &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")]);
+    }
+
+    #[test]
+    fn ulid_as_uuid_errors() {
+        assert_de_tokens_error::<AsUuid>(
+            &[Token::Str("11223344-5566-7788-99aa-bbccddeef00g")],
+            "invalid character 'g' at position 35",
+        );
+        assert_de_tokens_error::<AsUuid>(&[Token::Str("1122")], "invalid length 4, expected 26");
+        assert_de_tokens_error::<AsUuid>(
+            &[Token::U64(1)],
+            "invalid type: integer `1`, expected a UUID or ULID string",
+        );
+    }
+
+    #[test]
+    fn ulid_as_u128() {
+        let value = AsU128(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F));
+        let json = serde_json::to_string(&value).unwrap();
+
+        assert_eq!(json, "22774453838368691933757882222884352015");
+        assert_eq!(serde_json::from_str::<AsU128>(&json).unwrap(), value);
+
+        assert_de_tokens(&AsU128(Ulid::from(42)), &[Token::U64(42)]);
+    }
+
+    #[test]
+    fn ulid_as_u128_errors() {
+        assert_de_tokens_error::<AsU128>(
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")],
+            "invalid type: string \"0H48SM8NB6EY49KANVSKEYXW0F\", expected a ULID as u128",
+        );
+    }
+
+    #[test]
+    fn flexible() {
+        let value = Flexible(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F));
+
+        assert_de_tokens(&value, &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")]);
+        assert_de_tokens(
+            &value,
+            &[Token::Bytes(&[
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+                0xF0, 0x0F,
+            ])],
+        );
+        assert_de_tokens(
+            &value,
+            &[
+                Token::Seq { len: Some(16) },
+                Token::U8(0x11),
+                Token::U8(0x22),
+                Token::U8(0x33),
+                Token::U8(0x44),
+                Token::U8(0x55),
+                Token::U8(0x66),
+                Token::U8(0x77),
+                Token::U8(0x88),
+                Token::U8(0x99),
+                Token::U8(0xAA),
+                Token::U8(0xBB),
+                Token::U8(0xCC),
+                Token::U8(0xDD),
+                Token::U8(0xEE),
+                Token::U8(0xF0),
+                Token::U8(0x0F),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn flexible_errors() {
+        assert_de_tokens_error::<Flexible>(
+            &[Token::Str("0H48SM8NB6EY49KANUSKEYXW0F")],
+            "invalid character 'U' at position 17",
+        );
+        assert_de_tokens_error::<Flexible>(
+            &[Token::Bytes(&[0x11, 0x22])],
+            "invalid length 2, expected 16",
+        );
+        assert_de_tokens_error::<Flexible>(
+            &[Token::Seq { len: Some(1) }, Token::U8(0x11), Token::SeqEnd],
+            "invalid length 1, expected a ULID string or 16 ULID bytes",
+        );
+        assert_de_tokens_error::<Flexible>(
+            &[Token::U64(1)],
+            "invalid type: integer `1`, expected a ULID string or 16 ULID bytes",
+        );
+    }
+
+    #[test]
+    fn strict() {
+        use serde_test::Configure;
+
+        let value = Strict(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F));
+
+        assert_tokens(
+            &value.clone().readable(),
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXW0F")],
+        );
+        assert_tokens(
+            &value.compact(),
+            &[Token::Bytes(&[
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+                0xF0, 0x0F,
+            ])],
+        );
+    }
+
+    #[test]
+    fn strict_errors() {
+        assert_de_tokens_error::<serde_test::Readable<Strict>>(
+            &[Token::Str("0H48SM8NB6EY49KANVSKEYXWOF")],
+            "invalid character 'O' at position 24",
+        );
+        assert_de_tokens_error::<serde_test::Readable<Strict>>(
+            &[Token::Str("0h48SM8NB6EY49KANVSKEYXW0F")],
+            "invalid character 'h' at position 1",
+        );
+        assert_de_tokens_error::<serde_test::Readable<Strict>>(
+            &[Token::U64(1)],
+            "invalid type: integer `1`, expected a canonical ULID string",
+        );
+    }
+
+    #[test]
+    fn ulid_keys_as_str() {
+        use serde_test::Configure;
+
+        let mut map = std::collections::BTreeMap::new();
+        let _ = map.insert(Ulid::from(0), 0);
+        let _ = map.insert(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F), 1);
+        let value = KeysAsStr(map);
+
+        let tokens = [
+            Token::Map { len: Some(2) },
+            Token::Str("00000000000000000000000000"),
+            Token::U8(0),
+            Token::Str("0H48SM8NB6EY49KANVSKEYXW0F"),
+            Token::U8(1),
+            Token::MapEnd,
+        ];
+        assert_tokens(&value.clone().readable(), &tokens);
+        assert_tokens(&value.compact(), &tokens);
+    }
+
+    #[test]
+    fn ulid_keys_as_str_round_trips_in_binary_formats() {
+        let mut map = std::collections::BTreeMap::new();
+        let _ = map.insert(Ulid::from(0), 0);
+        let _ = map.insert(Ulid::from(0x1122_3344_5566_7788_99AA_BBCC_DDEE_F00F), 1);
+        let value = KeysAsStr(map);
+
+        let bytes = bincode1::serialize(&value).unwrap();
+        assert_eq!(bincode1::deserialize::<KeysAsStr>(&bytes).unwrap(), value);
+
+        let bytes = postcard::to_allocvec(&value).unwrap();
+        assert_eq!(postcard::from_bytes::<KeysAsStr>(&bytes).unwrap(), value);
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct HashKeysAsStr(
+            #[serde(with = "ulid_keys_as_str")] std::collections::HashMap<Ulid, String>,
+        );
+
+        let mut map = std::collections::HashMap::new();
+        let _ = map.insert(Ulid::from(1), String::from("one"));
+        let value = HashKeysAsStr(map);
+
+        let bytes = postcard::to_allocvec(&value).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<HashKeysAsStr>(&bytes).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn ulid_keys_as_str_errors() {
+        assert_de_tokens_error::<KeysAsStr>(
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("0H48SM8NB6EY49KANUSKEYXW0F"),
+            ],
+            "invalid character 'U' at position 17",
+        );
+        assert_de_tokens_error::<KeysAsStr>(
+            &[Token::Seq { len: Some(0) }],
+            "invalid type: sequence, expected a map with ULID string keys",
+        );
+    }
+}
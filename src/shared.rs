@@ -0,0 +1,333 @@
+//! Cloneable ULID generator for async services.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `tokio` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["tokio"]
+//! ```
+//!
+//! # Usage
+//!
+//! [`SharedUlidGenerator`] is cheap to clone and can be stored in the state of
+//! an async service. `generate()` is synchronous and only holds an internal
+//! lock for the few instructions needed to create the next ULID, so it never
+//! blocks across an `.await`.
+//!
+//! ```rust
+//! use rusty_ulid::shared::SharedUlidGenerator;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let generator = SharedUlidGenerator::new();
+//!
+//! let handle = tokio::spawn({
+//!     let generator = generator.clone();
+//!     async move { generator.generate() }
+//! });
+//!
+//! let first = handle.await.unwrap();
+//! let second = generator.generate();
+//!
+//! assert!(first < second);
+//! # }
+//! ```
+
+use crate::monotonic::{next_without_borrowing, WAIT_INTERVAL};
+use crate::{unix_epoch_ms, Ulid};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use tokio::sync::Notify;
+
+/// Cloneable generator creating strictly increasing ULIDs across all clones.
+///
+/// All clones share the same state, so ULIDs created by any of them are
+/// strictly increasing in the order of the `generate()` calls.
+///
+/// A generator created by [`with_prefill`](Self::with_prefill) keeps a buffer
+/// of ULIDs that is refilled on a background task, moving the cost of
+/// randomness and clock access off the request path.
+#[derive(Debug, Clone)]
+pub struct SharedUlidGenerator {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: Mutex<State>,
+    capacity: usize,
+    refill: Arc<Notify>,
+}
+
+#[derive(Debug)]
+struct State {
+    // the most recently created ULID, either returned or buffered
+    last: Option<Ulid>,
+    buffer: VecDeque<Ulid>,
+}
+
+impl SharedUlidGenerator {
+    /// Creates a new generator without a prefilled buffer.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new generator that keeps up to `capacity` ULIDs in a buffer,
+    /// refilled on a background task spawned on the current tokio runtime.
+    ///
+    /// The buffer is topped up once it falls below half of its capacity. The
+    /// background task ends after the last clone of the generator was dropped.
+    ///
+    /// Prefilled ULIDs carry the timestamp of their creation, so under low load
+    /// they can lag behind the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::shared::SharedUlidGenerator;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let generator = SharedUlidGenerator::with_prefill(1024);
+    ///
+    /// let ulids: Vec<_> = (0..10_000).map(|_| generator.generate()).collect();
+    ///
+    /// assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    #[must_use]
+    pub fn with_prefill(capacity: usize) -> Self {
+        let generator = Self::with_capacity(capacity);
+        if capacity > 0 {
+            drop(tokio::spawn(refill(
+                Arc::downgrade(&generator.inner),
+                Arc::clone(&generator.inner.refill),
+            )));
+            generator.inner.refill.notify_one();
+        }
+        generator
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    last: None,
+                    buffer: VecDeque::with_capacity(capacity),
+                }),
+                capacity,
+                refill: Arc::new(Notify::new()),
+            }),
+        }
+    }
+
+    /// Returns the next ULID.
+    ///
+    /// The ULID is taken from the prefilled buffer if available and created
+    /// on the spot otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    pub fn generate(&self) -> Ulid {
+        let mut state = self.inner.lock();
+
+        if let Some(ulid) = state.buffer.pop_front() {
+            if state.buffer.len() < self.inner.capacity / 2 {
+                self.inner.refill.notify_one();
+            }
+            return ulid;
+        }
+
+        state.next()
+    }
+
+    /// Returns the next ULID, waiting for the next millisecond instead of
+    /// borrowing it.
+    ///
+    /// Like [`generate`](Self::generate), but if the random part of the most
+    /// recently created ULID is exhausted, this function sleeps on the tokio
+    /// timer until the clock has passed its timestamp. No lock is held while
+    /// sleeping.
+    ///
+    /// ULIDs created by [`generate`](Self::generate) or taken from the prefilled
+    /// buffer may have borrowed future milliseconds, in which case this function
+    /// waits until the clock caught up with them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusty_ulid::shared::SharedUlidGenerator;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let generator = SharedUlidGenerator::new();
+    ///
+    /// let first = generator.generate_waiting().await;
+    /// let second = generator.generate_waiting().await;
+    ///
+    /// assert!(first < second);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z` or outside of a
+    /// tokio runtime with enabled time driver.
+    pub async fn generate_waiting(&self) -> Ulid {
+        loop {
+            if let Some(ulid) = self.try_generate_without_borrowing() {
+                return ulid;
+            }
+            tokio::time::sleep(WAIT_INTERVAL).await;
+        }
+    }
+
+    fn try_generate_without_borrowing(&self) -> Option<Ulid> {
+        let mut state = self.inner.lock();
+
+        if let Some(ulid) = state.buffer.pop_front() {
+            if state.buffer.len() < self.inner.capacity / 2 {
+                self.inner.refill.notify_one();
+            }
+            return Some(ulid);
+        }
+
+        let timestamp = unix_epoch_ms();
+        let ulid = match state.last {
+            Some(last) => next_without_borrowing(last, timestamp)?,
+            None => Ulid::from_timestamp_and_random_bits(timestamp, crate::random_bits()),
+        };
+        state.last = Some(ulid);
+        Some(ulid)
+    }
+
+    /// Returns the number of currently buffered ULIDs.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.inner.lock().buffer.len()
+    }
+}
+
+impl Default for SharedUlidGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // the state is always consistent, even if another thread panicked
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        // wake up the background task so it notices that it's no longer needed
+        self.refill.notify_one();
+    }
+}
+
+impl State {
+    fn next(&mut self) -> Ulid {
+        let timestamp = unix_epoch_ms();
+        let ulid = Ulid::next_strictly_increasing(self.last, timestamp, crate::random_bits);
+        self.last = Some(ulid);
+        ulid
+    }
+}
+
+async fn refill(inner: Weak<Inner>, notify: Arc<Notify>) {
+    loop {
+        notify.notified().await;
+
+        let Some(inner) = inner.upgrade() else {
+            return;
+        };
+
+        let mut state = inner.lock();
+        while state.buffer.len() < inner.capacity {
+            let ulid = state.next();
+            state.buffer.push_back(ulid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_state() {
+        let generator = SharedUlidGenerator::new();
+        let cloned = generator.clone();
+
+        let first = generator.generate();
+        let second = cloned.generate();
+        let third = generator.generate();
+
+        assert!(first < second);
+        assert!(second < third);
+        assert_eq!(generator.buffered(), 0);
+    }
+
+    #[tokio::test]
+    async fn prefill_fills_buffer() {
+        let generator = SharedUlidGenerator::with_prefill(16);
+        tokio::task::yield_now().await;
+
+        assert_eq!(generator.buffered(), 16);
+
+        let first = generator.generate();
+        assert_eq!(generator.buffered(), 15);
+
+        for _ in 0..8 {
+            let _ = generator.generate();
+        }
+        tokio::task::yield_now().await;
+        assert_eq!(generator.buffered(), 16);
+
+        // ULIDs created after the buffer are still larger
+        let mut state = generator.inner.lock();
+        let created = state.next();
+        assert!(first < created);
+        assert!(state.buffer.iter().all(|ulid| *ulid < created));
+    }
+
+    #[tokio::test]
+    async fn waiting_continues_in_next_millisecond() {
+        let generator = SharedUlidGenerator::new();
+        let last = Ulid::max_for_timestamp(unix_epoch_ms());
+        generator.inner.lock().last = Some(last);
+
+        let ulid = generator.generate_waiting().await;
+
+        assert!(last.timestamp() < ulid.timestamp());
+        assert_eq!(generator.inner.lock().last, Some(ulid));
+    }
+
+    #[tokio::test]
+    async fn background_task_ends_after_drop() {
+        let metrics = tokio::runtime::Handle::current().metrics();
+        let generator = SharedUlidGenerator::with_prefill(4);
+        tokio::task::yield_now().await;
+        assert_eq!(metrics.num_alive_tasks(), 1);
+
+        drop(generator);
+        tokio::task::yield_now().await;
+
+        assert_eq!(metrics.num_alive_tasks(), 0);
+    }
+}
@@ -0,0 +1,91 @@
+//! [slog](https://docs.rs/slog) support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `slog` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["slog"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Value`], so it can be attached to loggers and records
+//! directly. It is emitted as canonical string without allocating:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//! use slog::{info, o, Discard, Logger};
+//!
+//! let request_id = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let logger = Logger::root(Discard, o!("request_id" => request_id));
+//! info!(logger, "request received"; "parent_id" => Ulid::from(0));
+//! ```
+
+use crate::Ulid;
+use slog::{Key, Record, Serializer, Value};
+
+impl Value for Ulid {
+    fn serialize(
+        &self,
+        _record: &Record<'_>,
+        key: Key,
+        serializer: &mut dyn Serializer,
+    ) -> slog::Result {
+        serializer.emit_str(key, self.encode_to_str(&mut [0; 26]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ulid;
+    use slog::{info, o, Drain, Key, Logger, OwnedKVList, Record, Serializer, KV};
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<String>>>);
+
+    impl Serializer for Captured {
+        fn emit_arguments(&mut self, _key: Key, _value: &fmt::Arguments<'_>) -> slog::Result {
+            panic!("ULIDs are expected to be emitted as str");
+        }
+
+        fn emit_str(&mut self, key: Key, value: &str) -> slog::Result {
+            self.0.lock().unwrap().push(format!("{key}={value}"));
+            Ok(())
+        }
+    }
+
+    impl Drain for Captured {
+        type Ok = ();
+        type Err = slog::Error;
+
+        fn log(&self, record: &Record<'_>, values: &OwnedKVList) -> slog::Result {
+            let mut serializer = self.clone();
+            record.kv().serialize(record, &mut serializer)?;
+            values.serialize(record, &mut serializer)
+        }
+    }
+
+    #[test]
+    fn ulids_are_emitted_as_str() {
+        let captured = Captured::default();
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        let logger = Logger::root(captured.clone().fuse(), o!("request_id" => ulid));
+        info!(logger, "test"; "parent_id" => Ulid::from(0));
+
+        assert_eq!(
+            *captured.0.lock().unwrap(),
+            [
+                "parent_id=00000000000000000000000000",
+                "request_id=01CAH7NXGRDJNE9B1NY7PQGYV7"
+            ]
+        );
+    }
+}
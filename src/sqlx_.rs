@@ -0,0 +1,250 @@
+//! [SQLx](https://github.com/launchbadge/sqlx) column type support.
+//!
+//! # Enabling
+//!
+//! This module is only available when at least one of the `sqlx-postgres`,
+//! `sqlx-mysql` and `sqlx-sqlite` features is enabled. Enable the ones of the
+//! databases you use in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["sqlx-postgres"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Type`], [`Encode`] and [`Decode`] for the following databases:
+//!
+//! | Database | Feature         | Column types      | Encoded as                    |
+//! |----------|-----------------|-------------------|-------------------------------|
+//! | Postgres | `sqlx-postgres` | `UUID`, `BYTEA`   | 16 bytes                      |
+//! | MySQL    | `sqlx-mysql`    | `BINARY(16)`      | 16 bytes                      |
+//! | SQLite   | `sqlx-sqlite`   | `TEXT`, `BLOB`    | 26 character ULID string      |
+//!
+//! Binary values use the same big-endian byte order as `<[u8; 16]>::from(ulid)`,
+//! so they sort just like the ULIDs themselves. SQLite values are decoded from
+//! either a ULID string or a 16-byte blob.
+//!
+//! Postgres parameters are sent without a declared type, so the server infers
+//! `UUID` or `BYTEA` from the column they are compared with or inserted into.
+//! Where the type can't be inferred, e.g. in `SELECT $1`, cast the parameter
+//! explicitly like `$1::uuid`.
+//!
+//! ```rust,no_run
+//! # async fn example(pool: sqlx::PgPool) -> Result<(), sqlx::Error> {
+//! use rusty_ulid::Ulid;
+//!
+//! let id = Ulid::generate();
+//! sqlx::query("INSERT INTO events (id) VALUES ($1)")
+//!     .bind(id)
+//!     .execute(&pool)
+//!     .await?;
+//!
+//! let fetched: (Ulid,) = sqlx::query_as("SELECT id FROM events WHERE id = $1")
+//!     .bind(id)
+//!     .fetch_one(&pool)
+//!     .await?;
+//! assert_eq!(fetched.0, id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Ulid;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+#[cfg(feature = "sqlx-mysql")]
+use sqlx::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+#[cfg(feature = "sqlx-postgres")]
+use sqlx::postgres::types::Oid;
+#[cfg(feature = "sqlx-postgres")]
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+#[cfg(feature = "sqlx-sqlite")]
+use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use sqlx::{Decode, Encode, Type};
+
+// built-in type, so the OID is the same in every database
+#[cfg(feature = "sqlx-postgres")]
+const PG_UUID_OID: Oid = Oid(2950);
+
+// lets the server infer the type of a parameter, i.e. UUID or BYTEA
+#[cfg(feature = "sqlx-postgres")]
+const PG_UNSPECIFIED_OID: Oid = Oid(0);
+
+// UUID text is "0162a27a-f618-6caa-e4ac-35f1ed787b67", BYTEA text is "\x0162a27af6186caae4ac35f1ed787b67"
+#[cfg(feature = "sqlx-postgres")]
+fn from_pg_text(text: &str) -> Result<Ulid, BoxDynError> {
+    let hex = text.strip_prefix("\\x").unwrap_or(text).replace('-', "");
+    if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(format!("invalid ULID value {text:?}").into());
+    }
+    Ok(Ulid::from(u128::from_str_radix(&hex, 16)?))
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl Type<Postgres> for Ulid {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(PG_UNSPECIFIED_OID)
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::with_oid(PG_UUID_OID) || <[u8] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl Encode<'_, Postgres> for Ulid {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <[u8; 16] as Encode<Postgres>>::encode(<[u8; 16]>::from(*self), buf)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl<'r> Decode<'r, Postgres> for Ulid {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(Self::try_from(value.as_bytes()?)?),
+            PgValueFormat::Text => from_pg_text(value.as_str()?),
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl Type<MySql> for Ulid {
+    fn type_info() -> MySqlTypeInfo {
+        <[u8] as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <[u8] as Type<MySql>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl Encode<'_, MySql> for Ulid {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        <&[u8] as Encode<MySql>>::encode(&<[u8; 16]>::from(*self), buf)
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl<'r> Decode<'r, MySql> for Ulid {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(Self::try_from(<&[u8] as Decode<MySql>>::decode(value)?)?)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl Type<Sqlite> for Ulid {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <str as Type<Sqlite>>::compatible(ty) || <[u8] as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<'q> Encode<'q, Sqlite> for Ulid {
+    fn encode_by_ref(
+        &self,
+        args: &mut Vec<SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode(self.to_string(), args)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<'r> Decode<'r, Sqlite> for Ulid {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        // blob() returns the UTF-8 bytes for TEXT values
+        let bytes = <&[u8] as Decode<Sqlite>>::decode(value)?;
+        if bytes.len() == 16 {
+            return Ok(Self::try_from(bytes)?);
+        }
+        Ok(std::str::from_utf8(bytes)?.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn postgres_type_is_inferred_by_the_server() {
+        assert_eq!(
+            <Ulid as Type<Postgres>>::type_info(),
+            PgTypeInfo::with_oid(PG_UNSPECIFIED_OID)
+        );
+        assert!(<Ulid as Type<Postgres>>::compatible(&PgTypeInfo::with_oid(
+            PG_UUID_OID
+        )));
+        assert!(<Ulid as Type<Postgres>>::compatible(&<[u8] as Type<
+            Postgres,
+        >>::type_info(
+        )));
+    }
+
+    #[cfg(feature = "sqlx-mysql")]
+    #[test]
+    fn mysql_type_is_binary() {
+        assert!(<Ulid as Type<MySql>>::compatible(
+            &<[u8] as Type<MySql>>::type_info()
+        ));
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    #[test]
+    fn from_pg_text_test_cases() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        assert_eq!(
+            from_pg_text("0162a27a-f618-6caa-e4ac-35f1ed787b67").unwrap(),
+            ulid
+        );
+        assert_eq!(
+            from_pg_text("\\x0162a27af6186caae4ac35f1ed787b67").unwrap(),
+            ulid
+        );
+        assert!(from_pg_text("0162a27a-f618-6caa-e4ac-35f1ed787b6").is_err());
+        assert!(from_pg_text("+162a27a-f618-6caa-e4ac-35f1ed787b67").is_err());
+        assert!(from_pg_text("01ARYZ6S41TSV4RRFFQ69G5FAV").is_err());
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    #[tokio::test]
+    async fn sqlite_round_trip() {
+        use sqlx::{Connection, SqliteConnection};
+
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+        let (text, blob, column_type): (Ulid, Ulid, String) =
+            sqlx::query_as("SELECT ?1, ?2, typeof(?1)")
+                .bind(ulid)
+                .bind(&<[u8; 16]>::from(ulid)[..])
+                .fetch_one(&mut connection)
+                .await
+                .unwrap();
+
+        assert_eq!(text, ulid);
+        assert_eq!(blob, ulid);
+        assert_eq!(column_type, "text");
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    #[tokio::test]
+    async fn sqlite_rejects_invalid_values() {
+        use sqlx::{Connection, SqliteConnection};
+
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+        for query in ["SELECT X'0102'", "SELECT '01CAH7NXGRDJNE9B1NY7PQGYVU'"] {
+            let result: Result<(Ulid,), _> = sqlx::query_as(query).fetch_one(&mut connection).await;
+
+            assert!(result.is_err(), "{query}");
+        }
+    }
+}
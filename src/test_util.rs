@@ -0,0 +1,194 @@
+//! Clocks for deterministic unit tests of code that creates ULIDs.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `test-util` feature is enabled. It is
+//! usually only needed by tests, so enable it in the `dev-dependencies` of
+//! `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dev-dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["test-util"]
+//! ```
+//!
+//! # Usage
+//!
+//! All clocks implement the [`Clock`] trait, so they can be passed to
+//! `Ulid::generate_with_clock`. They also provide the timestamps passed to the
+//! rng-injection APIs like `Ulid::from_timestamp_with_rng` or
+//! `Ulid::next_monotonic_from_timestamp_with_rng`. Combined with a seeded or
+//! mocked rng, the created ULIDs are fully deterministic.
+//!
+//! ```rust
+//! # #[cfg(feature = "rand")]
+//! # {
+//! use rand::rngs::mock::StepRng;
+//! use rusty_ulid::test_util::SteppingClock;
+//! use rusty_ulid::Ulid;
+//!
+//! let clock = SteppingClock::new(1_523_144_390_168, 1);
+//! let mut rng = StepRng::new(0, 0);
+//!
+//! let first = Ulid::from_timestamp_with_rng(clock.timestamp(), &mut rng);
+//! let second = Ulid::from_timestamp_with_rng(clock.timestamp(), &mut rng);
+//!
+//! assert_eq!(first.to_string(), "01CAH7NXGR0000000000000000");
+//! assert_eq!(second.to_string(), "01CAH7NXGS0000000000000000");
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of timestamps in milliseconds since the UNIX epoch.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::test_util::{Clock, FixedClock, SteppingClock};
+///
+/// fn two_timestamps(clock: &impl Clock) -> (u64, u64) {
+///     (clock.timestamp(), clock.timestamp())
+/// }
+///
+/// assert_eq!(two_timestamps(&FixedClock::new(7)), (7, 7));
+/// assert_eq!(two_timestamps(&SteppingClock::new(7, 1)), (7, 8));
+/// ```
+pub trait Clock {
+    /// Returns the current timestamp in milliseconds since the UNIX epoch.
+    fn timestamp(&self) -> u64;
+}
+
+/// Clock that always returns the same timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::test_util::FixedClock;
+///
+/// let clock = FixedClock::new(1_523_144_390_168);
+///
+/// assert_eq!(clock.timestamp(), 1_523_144_390_168);
+/// assert_eq!(clock.timestamp(), 1_523_144_390_168);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock {
+    timestamp: u64,
+}
+
+impl FixedClock {
+    /// Creates a new clock that always returns `timestamp`.
+    #[must_use]
+    pub const fn new(timestamp: u64) -> Self {
+        Self { timestamp }
+    }
+
+    /// Returns the fixed timestamp in milliseconds since the UNIX epoch.
+    #[must_use]
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+/// Clock that advances by a fixed step every time it is read.
+///
+/// The first call of `timestamp()` returns the start timestamp, every following
+/// call returns a timestamp `step` milliseconds later than the previous one.
+/// The clock can be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::test_util::SteppingClock;
+///
+/// let clock = SteppingClock::new(1_000, 10);
+///
+/// assert_eq!(clock.timestamp(), 1_000);
+/// assert_eq!(clock.timestamp(), 1_010);
+/// assert_eq!(clock.timestamp(), 1_020);
+/// ```
+#[derive(Debug)]
+pub struct SteppingClock {
+    next: AtomicU64,
+    step: u64,
+}
+
+impl SteppingClock {
+    /// Creates a new clock starting at `start_timestamp` and advancing by `step`
+    /// milliseconds with every read.
+    #[must_use]
+    pub const fn new(start_timestamp: u64, step: u64) -> Self {
+        Self {
+            next: AtomicU64::new(start_timestamp),
+            step,
+        }
+    }
+
+    /// Returns the current timestamp in milliseconds since the UNIX epoch and
+    /// advances the clock by `step`.
+    ///
+    /// The clock wraps around on overflow of `u64`.
+    pub fn timestamp(&self) -> u64 {
+        self.next.fetch_add(self.step, Ordering::Relaxed)
+    }
+
+    /// Returns the timestamp the next call of `timestamp()` will return,
+    /// without advancing the clock.
+    #[must_use]
+    pub fn peek(&self) -> u64 {
+        self.next.load(Ordering::Relaxed)
+    }
+}
+
+impl Clock for FixedClock {
+    fn timestamp(&self) -> u64 {
+        Self::timestamp(self)
+    }
+}
+
+impl Clock for SteppingClock {
+    fn timestamp(&self) -> u64 {
+        Self::timestamp(self)
+    }
+}
+
+impl Clone for SteppingClock {
+    fn clone(&self) -> Self {
+        Self::new(self.peek(), self.step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepping_clock_peek_does_not_advance() {
+        let clock = SteppingClock::new(5, 2);
+
+        assert_eq!(clock.peek(), 5);
+        assert_eq!(clock.peek(), 5);
+        assert_eq!(clock.timestamp(), 5);
+        assert_eq!(clock.peek(), 7);
+    }
+
+    #[test]
+    fn stepping_clock_clone_continues_independently() {
+        let clock = SteppingClock::new(0, 1);
+        let _ = clock.timestamp();
+
+        let cloned = clock.clone();
+
+        assert_eq!(clock.timestamp(), 1);
+        assert_eq!(clock.timestamp(), 2);
+        assert_eq!(cloned.timestamp(), 1);
+    }
+
+    #[test]
+    fn stepping_clock_with_zero_step_is_fixed() {
+        let clock = SteppingClock::new(42, 0);
+
+        assert_eq!(clock.timestamp(), 42);
+        assert_eq!(clock.timestamp(), 42);
+    }
+}
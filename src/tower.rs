@@ -0,0 +1,225 @@
+//! [tower](https://docs.rs/tower) middleware assigning ULID request IDs.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `tower` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["tower"]
+//! ```
+//!
+//! # Usage
+//!
+//! [`UlidRequestIdLayer`] takes the ULID of the incoming `x-request-id` header,
+//! or generates a new one if the header is missing or not a valid ULID. The ULID
+//! is available to inner services as [`RequestId`] extension of the request and
+//! returned in the `x-request-id` header of the response:
+//!
+//! ```rust
+//! use http::{Request, Response};
+//! use rusty_ulid::tower::{RequestId, UlidRequestIdLayer};
+//! use std::convert::Infallible;
+//! use tower::{service_fn, ServiceBuilder, ServiceExt};
+//!
+//! async fn handler(request: Request<()>) -> Result<Response<String>, Infallible> {
+//!     let RequestId(id) = request.extensions().get::<RequestId>().copied().unwrap();
+//!     Ok(Response::new(format!("Handling request {id}")))
+//! }
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let service = ServiceBuilder::new()
+//!     .layer(UlidRequestIdLayer::new())
+//!     .service(service_fn(handler));
+//!
+//! let request = Request::builder()
+//!     .header("x-request-id", "01CAH7NXGRDJNE9B1NY7PQGYV7")
+//!     .body(())
+//!     .unwrap();
+//! let response = service.oneshot(request).await.unwrap();
+//!
+//! assert_eq!(response.headers()["x-request-id"], "01CAH7NXGRDJNE9B1NY7PQGYV7");
+//! assert_eq!(response.into_body(), "Handling request 01CAH7NXGRDJNE9B1NY7PQGYV7");
+//! # }
+//! ```
+
+use crate::{MonotonicUlids, Ulid};
+use http::{HeaderName, HeaderValue, Request, Response};
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+use tower_layer::Layer;
+use tower_service::Service;
+
+static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The ULID request ID, stored in the extensions of each request by [`UlidRequestId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestId(pub Ulid);
+
+/// Layer applying [`UlidRequestId`] to services.
+///
+/// Generated request IDs are strictly increasing across all services created by
+/// this layer and its clones.
+#[derive(Debug, Clone, Default)]
+pub struct UlidRequestIdLayer {
+    ulids: Arc<Mutex<MonotonicUlids>>,
+}
+
+impl UlidRequestIdLayer {
+    /// Creates a new layer.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<S> Layer<S> for UlidRequestIdLayer {
+    type Service = UlidRequestId<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        UlidRequestId {
+            inner,
+            ulids: Arc::clone(&self.ulids),
+        }
+    }
+}
+
+/// Middleware assigning a ULID request ID to each request, see [`UlidRequestIdLayer`].
+#[derive(Debug, Clone)]
+pub struct UlidRequestId<S> {
+    inner: S,
+    ulids: Arc<Mutex<MonotonicUlids>>,
+}
+
+impl<S> UlidRequestId<S> {
+    fn generate(&self) -> Ulid {
+        // the state is always consistent, even if another thread panicked
+        self.ulids
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .next()
+            .expect("MonotonicUlids is endless")
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for UlidRequestId<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let id = request
+            .headers()
+            .get(&X_REQUEST_ID)
+            .and_then(|value| Ulid::try_from(value).ok())
+            .unwrap_or_else(|| self.generate());
+        let _ = request
+            .headers_mut()
+            .insert(&X_REQUEST_ID, HeaderValue::from(id));
+        let _ = request.extensions_mut().insert(RequestId(id));
+
+        ResponseFuture {
+            inner: self.inner.call(request),
+            id,
+        }
+    }
+}
+
+pin_project! {
+    /// Response future of [`UlidRequestId`].
+    #[derive(Debug)]
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+        id: Ulid,
+    }
+}
+
+impl<F, ResBody, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<ResBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut response = ready!(this.inner.poll(cx))?;
+        let _ = response
+            .headers_mut()
+            .insert(&X_REQUEST_ID, HeaderValue::from(*this.id));
+        Poll::Ready(Ok(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::{service_fn, ServiceExt};
+
+    async fn call(request: Request<()>) -> (Response<()>, Ulid) {
+        let service =
+            UlidRequestIdLayer::new().layer(service_fn(|request: Request<()>| async move {
+                let RequestId(id) = *request.extensions().get::<RequestId>().unwrap();
+                assert_eq!(Ulid::try_from(&request.headers()[&X_REQUEST_ID]), Ok(id));
+                Ok::<_, Infallible>(Response::new(()))
+            }));
+        let response = service.oneshot(request).await.unwrap();
+        let id = Ulid::try_from(&response.headers()[&X_REQUEST_ID]).unwrap();
+        (response, id)
+    }
+
+    #[tokio::test]
+    async fn valid_request_id_is_kept() {
+        let request = Request::builder()
+            .header("x-request-id", "01cah7nxgrdjne9b1ny7pqgyv7")
+            .body(())
+            .unwrap();
+
+        let (response, id) = call(request).await;
+
+        assert_eq!(id, Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+        assert_eq!(
+            response.headers()["x-request-id"],
+            "01CAH7NXGRDJNE9B1NY7PQGYV7"
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_request_id_is_generated() {
+        let (_, first) = call(Request::new(())).await;
+        let request = Request::builder()
+            .header("x-request-id", "nope")
+            .body(())
+            .unwrap();
+        let (_, second) = call(request).await;
+
+        assert_ne!(first, second);
+        assert_ne!(first.timestamp(), 0);
+    }
+
+    #[test]
+    fn generated_request_ids_are_strictly_increasing() {
+        let layer = UlidRequestIdLayer::new();
+        let first = layer.layer(());
+        let second = layer.clone().layer(());
+
+        let ulids = [first.generate(), second.generate(), first.generate()];
+
+        assert!(ulids[0] < ulids[1]);
+        assert!(ulids[1] < ulids[2]);
+    }
+}
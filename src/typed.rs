@@ -0,0 +1,253 @@
+//! ULIDs tagged with the type of the entity they identify.
+//!
+//! [`TypedUlid`] wraps a `Ulid` together with a marker type, so that IDs of
+//! different entities can't be mixed up at compile time:
+//!
+//! ```rust
+//! use rusty_ulid::TypedUlid;
+//!
+//! enum User {}
+//! enum Order {}
+//!
+//! type UserId = TypedUlid<User>;
+//! type OrderId = TypedUlid<Order>;
+//!
+//! fn cancel(order: OrderId) {
+//!     # let _ = order;
+//! }
+//!
+//! let order_id: OrderId = "01CAH7NXGRDJNE9B1NY7PQGYV7".parse()?;
+//! cancel(order_id);
+//!
+//! let user_id: UserId = "01CAH7NXGRDJNE9B1NY7PQGYV7".parse()?;
+//! // cancel(user_id); // doesn't compile
+//! # let _ = user_id;
+//! # Ok::<(), rusty_ulid::DecodingError>(())
+//! ```
+//!
+//! Formatting, parsing and serialization behave exactly like the ones of the
+//! wrapped `Ulid`.
+
+use crate::{DecodingError, Ulid};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// A `Ulid` tagged with the marker type `T`.
+///
+/// `T` is only used at compile time, it doesn't need to implement any trait
+/// and is usually an uninhabited `enum`. A `TypedUlid` has the same size as a
+/// `Ulid` and implements `Copy`, `Eq`, `Ord`, `Hash`, `Send` and `Sync`
+/// regardless of `T`.
+///
+/// # Examples
+///
+/// ```
+/// use rusty_ulid::{TypedUlid, Ulid};
+///
+/// enum User {}
+///
+/// let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+/// let user_id = TypedUlid::<User>::from_ulid(ulid);
+///
+/// assert_eq!(user_id.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+/// assert_eq!(user_id.ulid(), ulid);
+/// ```
+#[repr(transparent)]
+pub struct TypedUlid<T: ?Sized> {
+    ulid: Ulid,
+    // fn() -> T keeps TypedUlid Send, Sync and covariant for any T
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized> TypedUlid<T> {
+    /// Creates a new typed ULID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn generate() -> Self {
+        Self::from_ulid(Ulid::generate())
+    }
+
+    /// Creates the next monotonic typed ULID for the given `previous`, see
+    /// [`Ulid::next_monotonic`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after `+10889-08-02T05:31:50.655Z`.
+    #[cfg(all(
+        any(feature = "rand", feature = "rand09", feature = "getrandom"),
+        any(feature = "chrono", feature = "time")
+    ))]
+    #[must_use]
+    pub fn next_monotonic(previous: Self) -> Self {
+        Self::from_ulid(Ulid::next_monotonic(previous.ulid))
+    }
+
+    /// Tags the given `ulid` with the marker type `T`.
+    #[must_use]
+    pub const fn from_ulid(ulid: Ulid) -> Self {
+        Self {
+            ulid,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the untyped `Ulid`.
+    #[must_use]
+    pub const fn ulid(self) -> Ulid {
+        self.ulid
+    }
+
+    /// Returns the timestamp of the wrapped `Ulid`, see [`Ulid::timestamp`].
+    #[must_use]
+    pub fn timestamp(&self) -> u64 {
+        self.ulid.timestamp()
+    }
+}
+
+impl<T: ?Sized> Clone for TypedUlid<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for TypedUlid<T> {}
+
+impl<T: ?Sized> PartialEq for TypedUlid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ulid == other.ulid
+    }
+}
+
+impl<T: ?Sized> Eq for TypedUlid<T> {}
+
+impl<T: ?Sized> PartialOrd for TypedUlid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for TypedUlid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ulid.cmp(&other.ulid)
+    }
+}
+
+impl<T: ?Sized> Hash for TypedUlid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ulid.hash(state);
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for TypedUlid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedUlid").field(&self.ulid).finish()
+    }
+}
+
+impl<T: ?Sized> fmt::Display for TypedUlid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.ulid, f)
+    }
+}
+
+impl<T: ?Sized> FromStr for TypedUlid<T> {
+    type Err = DecodingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ulid::from_str(s).map(Self::from_ulid)
+    }
+}
+
+impl<T: ?Sized> From<Ulid> for TypedUlid<T> {
+    fn from(ulid: Ulid) -> Self {
+        Self::from_ulid(ulid)
+    }
+}
+
+impl<T: ?Sized> From<TypedUlid<T>> for Ulid {
+    fn from(typed: TypedUlid<T>) -> Self {
+        typed.ulid
+    }
+}
+
+impl<T: ?Sized> AsRef<Ulid> for TypedUlid<T> {
+    fn as_ref(&self) -> &Ulid {
+        &self.ulid
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized> serde::Serialize for TypedUlid<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.ulid, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ?Sized> serde::Deserialize<'de> for TypedUlid<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <Ulid as serde::Deserialize>::deserialize(deserializer).map(Self::from_ulid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum User {}
+
+    #[test]
+    fn behaves_like_ulid() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let typed = TypedUlid::<User>::from(ulid);
+
+        assert_eq!(typed.to_string(), ulid.to_string());
+        assert_eq!(format!("{typed:>30}"), format!("{ulid:>30}"));
+        assert_eq!(typed.to_string().parse(), Ok(typed));
+        assert_eq!(Ulid::from(typed), ulid);
+        assert_eq!(typed.timestamp(), ulid.timestamp());
+        assert!(typed < TypedUlid::from_ulid(ulid.increment()));
+    }
+
+    #[test]
+    fn parse_errors_are_forwarded() {
+        assert_eq!(
+            "01CAH7NXGRDJNE9B1NY7PQGYVU".parse::<TypedUlid<User>>(),
+            Err(DecodingError::InvalidChar {
+                character: 'U',
+                position: 25,
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_matches_ulid() {
+        use serde_test::{assert_tokens, Configure, Token};
+
+        let typed =
+            TypedUlid::<User>::from_ulid(Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+
+        assert_tokens(
+            &typed.readable(),
+            &[Token::Str("01CAH7NXGRDJNE9B1NY7PQGYV7")],
+        );
+        assert_tokens(
+            &typed.compact(),
+            &[Token::Bytes(&[
+                0x01, 0x62, 0xA2, 0x7A, 0xF6, 0x18, 0x6C, 0xAA, 0xE4, 0xAC, 0x35, 0xF1, 0xED, 0x78,
+                0x7B, 0x67,
+            ])],
+        );
+    }
+}
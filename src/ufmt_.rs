@@ -0,0 +1,58 @@
+//! [ufmt](https://docs.rs/ufmt) support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `ufmt` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["ufmt"]
+//! ```
+//!
+//! The feature doesn't require `std`, so it can be combined with
+//! `default-features = false` on `no_std` targets.
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`uDisplay`], writing the canonical string without the
+//! `core::fmt` machinery:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! let mut line = String::new();
+//! ufmt::uwrite!(line, "id={}", ulid).unwrap();
+//!
+//! assert_eq!(line, "id=01CAH7NXGRDJNE9B1NY7PQGYV7");
+//! ```
+
+use crate::Ulid;
+use ufmt::{uDisplay, uWrite, Formatter};
+
+impl uDisplay for Ulid {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(self.encode_to_str(&mut [0; 26]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ulid;
+
+    #[test]
+    fn udisplay_matches_display() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+
+        let mut formatted = String::new();
+        ufmt::uwrite!(formatted, "{}", ulid).unwrap();
+
+        assert_eq!(formatted, ulid.to_string());
+    }
+}
@@ -0,0 +1,95 @@
+//! [valuable](https://docs.rs/valuable) support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `valuable` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["valuable"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Valuable`] as a tuple struct named `Ulid` containing the
+//! canonical string, so structured consumers see a ULID instead of an opaque
+//! string or number:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//! use valuable::Valuable;
+//!
+//! let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//!
+//! assert_eq!(
+//!     format!("{:?}", ulid.as_value()),
+//!     r#"Ulid("01CAH7NXGRDJNE9B1NY7PQGYV7")"#
+//! );
+//! ```
+//!
+//! # Tracing
+//!
+//! `tracing` only records `Valuable` fields when built with
+//! `RUSTFLAGS="--cfg tracing_unstable"` and its `valuable` feature, using
+//! `tracing::field::valuable(&ulid)`. Without it, record ULIDs with
+//! `tracing::field::display(ulid)`, or the `%ulid` shorthand of the macros, which
+//! formats them as canonical string.
+
+use crate::Ulid;
+use valuable::{Fields, StructDef, Structable, Valuable, Value, Visit};
+
+impl Valuable for Ulid {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        let mut buffer = [0; 26];
+        visit.visit_unnamed_fields(&[Value::String(self.encode_to_str(&mut buffer))]);
+    }
+}
+
+impl Structable for Ulid {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Ulid", Fields::Unnamed(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ulid;
+    use valuable::{Structable, Value, Visit};
+
+    #[derive(Default)]
+    struct Strings(Vec<String>);
+
+    impl Visit for Strings {
+        fn visit_value(&mut self, value: Value<'_>) {
+            if let Value::Structable(structable) = value {
+                structable.visit(self);
+            }
+        }
+
+        fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+            for value in values {
+                if let Value::String(string) = value {
+                    self.0.push((*string).to_owned());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn visits_canonical_string() {
+        let ulid = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+        let mut strings = Strings::default();
+
+        valuable::visit(&ulid, &mut strings);
+
+        assert_eq!(strings.0, ["01CAH7NXGRDJNE9B1NY7PQGYV7"]);
+        assert_eq!(ulid.definition().name(), "Ulid");
+        assert!(ulid.definition().is_static());
+    }
+}
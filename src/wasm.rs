@@ -0,0 +1,90 @@
+//! [wasm-bindgen](https://rustwasm.github.io/docs/wasm-bindgen/) entry points.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `wasm` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["wasm"]
+//! ```
+//!
+//! # Usage
+//!
+//! On `wasm32-unknown-unknown`, the `wasm` feature uses `Date.now()` as clock
+//! and `crypto.getRandomValues()` as source of randomness, so [`Ulid::generate`]
+//! and friends work in browsers and Node.js.
+//!
+//! The functions of this module are exported to JavaScript:
+//!
+//! ```js
+//! import { generateUlid, ulidTimestamp } from "rusty_ulid";
+//!
+//! const ulid = generateUlid();
+//! console.log(ulid, new Date(ulidTimestamp(ulid)));
+//! ```
+
+use crate::Ulid;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Returns a new ULID string.
+///
+/// Exported to JavaScript as `generateUlid()`.
+///
+/// # Example
+/// ```
+/// let ulid_string = rusty_ulid::wasm::generate_ulid();
+///
+/// assert_eq!(ulid_string.len(), 26);
+/// ```
+#[wasm_bindgen(js_name = generateUlid)]
+#[must_use]
+pub fn generate_ulid() -> String {
+    Ulid::generate().to_string()
+}
+
+/// Returns the next monotonic ULID string for the given `previous` ULID string.
+///
+/// Returns a new ULID string if `previous` is not a valid ULID.
+///
+/// Exported to JavaScript as `nextMonotonicUlid(previous)`.
+///
+/// # Example
+/// ```
+/// let first = rusty_ulid::wasm::generate_ulid();
+/// let second = rusty_ulid::wasm::next_monotonic_ulid(&first);
+///
+/// assert!(first < second);
+/// ```
+#[wasm_bindgen(js_name = nextMonotonicUlid)]
+#[must_use]
+pub fn next_monotonic_ulid(previous: &str) -> String {
+    match previous.parse() {
+        Ok(previous) => Ulid::next_monotonic(previous).to_string(),
+        Err(_) => generate_ulid(),
+    }
+}
+
+/// Returns the timestamp of the given ULID string in milliseconds since the
+/// UNIX epoch, or `None` (i.e. `undefined`) if the string isn't a valid ULID.
+///
+/// The value can be passed to the JavaScript `Date` constructor.
+///
+/// Exported to JavaScript as `ulidTimestamp(ulid)`.
+///
+/// # Example
+/// ```
+/// let timestamp = rusty_ulid::wasm::ulid_timestamp("01CAH7NXGRDJNE9B1NY7PQGYV7");
+///
+/// assert_eq!(timestamp, Some(1_523_144_390_168.0));
+/// assert_eq!(rusty_ulid::wasm::ulid_timestamp("nope"), None);
+/// ```
+#[wasm_bindgen(js_name = ulidTimestamp)]
+#[must_use]
+pub fn ulid_timestamp(ulid: &str) -> Option<f64> {
+    ulid.parse::<Ulid>()
+        .ok()
+        .map(|ulid| ulid.timestamp() as f64)
+}
@@ -0,0 +1,79 @@
+//! [zeroize](https://docs.rs/zeroize) support.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `zeroize` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rusty_ulid]
+//! version = "2"
+//! features = ["zeroize"]
+//! ```
+//!
+//! # Usage
+//!
+//! `Ulid` implements [`Zeroize`], so a ULID used as a token can be scrubbed
+//! from memory together with the rest of a credentials struct:
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//! use zeroize::Zeroize;
+//!
+//! let mut token = Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67);
+//! token.zeroize();
+//!
+//! assert_eq!(token, Ulid::from(0));
+//! ```
+//!
+//! `Ulid` is `Copy` and therefore can't be zeroized on drop by itself. Wrap it
+//! in [`Zeroizing`](zeroize::Zeroizing) to opt into
+//! [`ZeroizeOnDrop`](zeroize::ZeroizeOnDrop):
+//!
+//! ```rust
+//! use rusty_ulid::Ulid;
+//! use zeroize::Zeroizing;
+//!
+//! let token = Zeroizing::new(Ulid::from(0x0162_A27A_F618_6CAA_E4AC_35F1_ED78_7B67));
+//!
+//! assert_eq!(token.to_string(), "01CAH7NXGRDJNE9B1NY7PQGYV7");
+//! // the ULID is zeroized when token goes out of scope
+//! ```
+//!
+//! Note that every copy of a ULID, e.g. one returned by a function or stored
+//! in another struct, has to be zeroized separately.
+
+use crate::Ulid;
+use zeroize::Zeroize;
+
+impl Zeroize for Ulid {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ulid;
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+    #[test]
+    fn zeroize_clears_all_bytes() {
+        let mut ulid = Ulid::from(u128::MAX);
+
+        ulid.zeroize();
+
+        assert_eq!(ulid.as_bytes(), &[0; 16]);
+    }
+
+    #[test]
+    fn zeroizing_wrapper_zeroizes_on_drop() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+        let mut token = Zeroizing::new(Ulid::from(u128::MAX));
+        assert_zeroize_on_drop(&token);
+
+        token.zeroize();
+        assert_eq!(*token, Ulid::from(0));
+    }
+}
@@ -0,0 +1,62 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use rusty_ulid::axum::{UlidParam, UlidRejection};
+use rusty_ulid::DecodingError;
+use tower::ServiceExt;
+
+async fn user(UlidParam(id): UlidParam) -> String {
+    id.to_string()
+}
+
+async fn custom(id: Result<UlidParam, UlidRejection>) -> impl IntoResponse {
+    match id {
+        Ok(UlidParam(id)) => (StatusCode::OK, id.to_string()),
+        Err(rejection) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("{:?}", rejection.decoding_error()),
+        ),
+    }
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/users/{id}", get(user))
+        .route("/custom/{id}", get(custom))
+}
+
+async fn request(uri: &str) -> (StatusCode, String) {
+    let response = app()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_valid_ulid() {
+    let (status, body) = request("/users/01ARZ3NDEKTSV4RRFFQ69G5FAV").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+}
+
+#[tokio::test]
+async fn test_invalid_ulid() {
+    let (status, body) = request("/users/01ARZ3NDEKTSV4RRFFQ69G5FA").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, "invalid ULID: invalid length 25, expected 26");
+}
+
+#[tokio::test]
+async fn test_custom_rejection() {
+    let (status, body) = request("/custom/81ARZ3NDEKTSV4RRFFQ69G5FAV").await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body, format!("{:?}", Some(DecodingError::DataTypeOverflow)));
+}